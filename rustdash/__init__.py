@@ -22,19 +22,110 @@ from rustdash._rustdash import (
     sum_by,
     min_by,
     max_by,
+    mean_by,
+    weighted_mean,
+    geometric_mean,
+    harmonic_mean,
+    cumsum,
+    cumprod,
+    cummax,
+    cummin,
+    diff,
+    pct_change,
+    rolling,
+    ewm_mean,
+    covariance,
+    correlation,
+    linear_regression,
+    histogram,
+    digitize,
+    bin_by,
+    RunningStats,
+    clamp,
+    in_range,
+    range,
+    range_right,
+    linspace,
+    times,
+    format_number,
+    format_bytes,
+    format_compact,
+    format_percent,
+    format_duration,
+    parse_number,
+    parse_bytes,
+    normalize,
+    standardize,
+    robust_scale,
+    rescale,
+    argmax,
+    argmin,
+    argmax_by,
+    argmin_by,
+    min_max,
+    min_max_by,
+    top_k,
+    gcd,
+    lcm,
+    factorial,
+    n_choose_k,
+    mod_pow,
+    is_prime,
+    primes_up_to,
+    interp,
+    lerp,
+    fill_gaps,
+    resample,
+    # Random
+    random,
+    shuffle,
+    sample,
+    sample_size,
+    weighted_choice,
+    reservoir_sample,
+    Random,
     # Arrays
     chunk,
+    windowed,
+    pairwise,
+    split_at,
+    split_when,
     compact,
+    flatten,
     flatten_deep,
     unique,
+    unique_by,
+    unique_with,
     group_by,
+    count_by,
+    key_by,
+    partition,
+    aggregate,
+    join,
     map,
     filter,
     find,
     sort_by,
+    order_by,
     reduce,
     zip,
+    zip_longest,
+    unzip,
+    zip_with,
+    zip_object,
+    zip_object_deep,
     intersection,
+    intersection_by,
+    intersection_with,
+    difference,
+    difference_by,
+    difference_with,
+    union,
+    union_by,
+    union_with,
+    xor,
+    xor_by,
+    xor_with,
     # Objects
     get,
     has,
@@ -69,19 +160,110 @@ __all__ = [
     "sum_by",
     "min_by",
     "max_by",
+    "mean_by",
+    "weighted_mean",
+    "geometric_mean",
+    "harmonic_mean",
+    "cumsum",
+    "cumprod",
+    "cummax",
+    "cummin",
+    "diff",
+    "pct_change",
+    "rolling",
+    "ewm_mean",
+    "covariance",
+    "correlation",
+    "linear_regression",
+    "histogram",
+    "digitize",
+    "bin_by",
+    "RunningStats",
+    "clamp",
+    "in_range",
+    "range",
+    "range_right",
+    "linspace",
+    "times",
+    "format_number",
+    "format_bytes",
+    "format_compact",
+    "format_percent",
+    "format_duration",
+    "parse_number",
+    "parse_bytes",
+    "normalize",
+    "standardize",
+    "robust_scale",
+    "rescale",
+    "argmax",
+    "argmin",
+    "argmax_by",
+    "argmin_by",
+    "min_max",
+    "min_max_by",
+    "top_k",
+    "gcd",
+    "lcm",
+    "factorial",
+    "n_choose_k",
+    "mod_pow",
+    "is_prime",
+    "primes_up_to",
+    "interp",
+    "lerp",
+    "fill_gaps",
+    "resample",
+    # Random
+    "random",
+    "shuffle",
+    "sample",
+    "sample_size",
+    "weighted_choice",
+    "reservoir_sample",
+    "Random",
     # Arrays
     "chunk",
+    "windowed",
+    "pairwise",
+    "split_at",
+    "split_when",
     "compact",
+    "flatten",
     "flatten_deep",
     "unique",
+    "unique_by",
+    "unique_with",
     "group_by",
+    "count_by",
+    "key_by",
+    "partition",
+    "aggregate",
+    "join",
     "map",
     "filter",
     "find",
     "sort_by",
+    "order_by",
     "reduce",
     "zip",
+    "zip_longest",
+    "unzip",
+    "zip_with",
+    "zip_object",
+    "zip_object_deep",
     "intersection",
+    "intersection_by",
+    "intersection_with",
+    "difference",
+    "difference_by",
+    "difference_with",
+    "union",
+    "union_by",
+    "union_with",
+    "xor",
+    "xor_by",
+    "xor_with",
     # Objects
     "get",
     "has",
@@ -1,6 +1,7 @@
 """Type stubs for the native Rust extension module."""
 
-from typing import Any, Callable, Dict, List, Optional, Sequence, TypeVar, Union
+from array import array
+from typing import Any, Callable, Dict, Iterable, List, Optional, Sequence, Tuple, TypeVar, Union
 
 # ─── Strings ─────────────────────────────────────────────────────────────────
 
@@ -18,29 +19,259 @@ def words(input: Union[str, List[str]]) -> Union[str, List[str]]: ...
 
 # ─── Numbers ─────────────────────────────────────────────────────────────────
 
-def sum(values: List[Union[int, float]]) -> Union[int, float]: ...
-def mean(values: List[Union[int, float]]) -> float: ...
-def min(values: List[Union[int, float]]) -> Union[int, float]: ...
-def max(values: List[Union[int, float]]) -> Union[int, float]: ...
-def round(value: Union[float, List[float]], decimals: int) -> Union[float, List[float]]: ...
-def sum_by(values: List[Any], fn: Callable[[Any], float]) -> float: ...
-def min_by(values: List[Any], fn: Callable[[Any], float]) -> Optional[Any]: ...
-def max_by(values: List[Any], fn: Callable[[Any], float]) -> Optional[Any]: ...
+# A list of numbers, any int64/float64 buffer (numpy array, array.array, memoryview),
+# or bytes/bytearray/byte memoryview holding float64 values.
+Numbers = Union[List[Union[int, float]], "array[Any]", memoryview, bytes, bytearray]
+
+def sum(
+    values: Numbers, nan_policy: str = "propagate", skip_none: bool = False
+) -> Union[int, float]: ...
+def mean(
+    values: Numbers, nan_policy: str = "propagate", skip_none: bool = False
+) -> Optional[float]: ...
+def min(
+    values: Numbers, nan_policy: str = "propagate", skip_none: bool = False
+) -> Optional[Union[int, float]]: ...
+def max(
+    values: Numbers, nan_policy: str = "propagate", skip_none: bool = False
+) -> Optional[Union[int, float]]: ...
+def round(
+    value: Union[float, Numbers],
+    decimals: int,
+    nan_policy: str = "propagate",
+    skip_none: bool = False,
+) -> Union[float, List[float]]: ...
+def sum_by(
+    values: List[Any],
+    fn: Union[str, Callable[[Any], Optional[float]]],
+    nan_policy: str = "propagate",
+    skip_none: bool = False,
+) -> float: ...
+def min_by(
+    values: List[Any],
+    fn: Union[str, Callable[[Any], Any]],
+    nan_policy: str = "propagate",
+    skip_none: bool = False,
+) -> Optional[Any]: ...
+def max_by(
+    values: List[Any],
+    fn: Union[str, Callable[[Any], Any]],
+    nan_policy: str = "propagate",
+    skip_none: bool = False,
+) -> Optional[Any]: ...
+def mean_by(
+    values: List[Any],
+    fn: Union[str, Callable[[Any], Optional[float]]],
+    nan_policy: str = "propagate",
+    skip_none: bool = False,
+) -> Optional[float]: ...
+def weighted_mean(values: Numbers, weights: Numbers) -> Optional[float]: ...
+def geometric_mean(values: Numbers) -> Optional[float]: ...
+def harmonic_mean(values: Numbers) -> Optional[float]: ...
+def cumsum(values: Numbers, nan_policy: str = "propagate") -> List[Union[int, float]]: ...
+def cumprod(values: Numbers, nan_policy: str = "propagate") -> List[Union[int, float]]: ...
+def cummax(values: Numbers, nan_policy: str = "propagate") -> List[Union[int, float]]: ...
+def cummin(values: Numbers, nan_policy: str = "propagate") -> List[Union[int, float]]: ...
+def diff(values: Numbers, lag: int = 1, nan_policy: str = "propagate") -> List[Union[int, float]]: ...
+def pct_change(values: Numbers, lag: int = 1, nan_policy: str = "propagate") -> List[float]: ...
+def rolling(
+    values: Numbers, window: int, stat: str = "mean", nan_policy: str = "propagate"
+) -> List[float]: ...
+def ewm_mean(values: Numbers, alpha: float, nan_policy: str = "propagate") -> List[float]: ...
+def covariance(xs: Numbers, ys: Numbers) -> float: ...
+def correlation(xs: Numbers, ys: Numbers, method: str = "pearson") -> float: ...
+def linear_regression(xs: Numbers, ys: Numbers) -> Dict[str, float]: ...
+def histogram(
+    values: Numbers,
+    bins: Union[int, str, Sequence[float], None] = None,
+    range: Optional[Tuple[float, float]] = None,
+) -> Tuple[List[int], List[float]]: ...
+def digitize(values: Numbers, edges: Numbers) -> List[int]: ...
+def bin_by(
+    values: List[Any], fn: Union[str, Callable[[Any], float]], edges: Numbers
+) -> Dict[Optional[int], List[Any]]: ...
+def clamp(
+    value: Union[float, Numbers], lower: float, upper: float
+) -> Union[int, float, List[Union[int, float]]]: ...
+def in_range(
+    value: Union[float, Numbers], start: float, end: Optional[float] = None
+) -> Union[bool, List[bool]]: ...
+def range(
+    start: float, end: Optional[float] = None, step: Optional[float] = None
+) -> List[Union[int, float]]: ...
+def range_right(
+    start: float, end: Optional[float] = None, step: Optional[float] = None
+) -> List[Union[int, float]]: ...
+def linspace(start: float, stop: float, num: int = 50, endpoint: bool = True) -> List[float]: ...
+def times(n: int, fn: Optional[Callable[[int], Any]] = None) -> List[Any]: ...
+def normalize(values: Numbers, fill: Optional[float] = None) -> List[float]: ...
+def standardize(values: Numbers, fill: Optional[float] = None) -> List[float]: ...
+def robust_scale(values: Numbers, fill: Optional[float] = None) -> List[float]: ...
+def rescale(
+    values: Numbers, new_min: float = 0.0, new_max: float = 1.0, fill: Optional[float] = None
+) -> List[float]: ...
+def argmax(values: Numbers) -> Optional[int]: ...
+def argmin(values: Numbers) -> Optional[int]: ...
+def argmax_by(values: List[Any], fn: Union[str, Callable[[Any], float]]) -> Optional[int]: ...
+def argmin_by(values: List[Any], fn: Union[str, Callable[[Any], float]]) -> Optional[int]: ...
+def min_max(values: Numbers) -> Optional[Tuple[Union[int, float], Union[int, float]]]: ...
+def min_max_by(
+    values: List[Any], fn: Union[str, Callable[[Any], float]]
+) -> Optional[Tuple[Any, Any]]: ...
+def top_k(
+    values: Union[Numbers, List[Any]],
+    k: int,
+    key: Union[str, Callable[[Any], float], None] = None,
+    largest: bool = True,
+) -> List[Any]: ...
+def gcd(values: Union[List[int], Numbers]) -> int: ...
+def lcm(values: Union[List[int], Numbers]) -> int: ...
+def factorial(n: int) -> int: ...
+def n_choose_k(n: int, k: int) -> int: ...
+def mod_pow(base: int, exp: int, modulus: int) -> int: ...
+def is_prime(n: Union[int, List[int]]) -> Union[bool, List[bool]]: ...
+def primes_up_to(n: int) -> List[int]: ...
+def interp(x: Union[float, Numbers], xp: Numbers, fp: Numbers) -> Union[float, List[float]]: ...
+def lerp(a: float, b: float, t: Union[float, Numbers]) -> Union[float, List[float]]: ...
+def fill_gaps(values: List[Optional[float]], method: str = "linear") -> List[Optional[float]]: ...
+def resample(
+    points: List[Tuple[Union[int, float], float]], step: Union[int, float], agg: str = "mean"
+) -> List[Tuple[Union[int, float], Optional[float]]]: ...
+def format_number(
+    value: Union[float, Numbers], decimals: int = 0, thousands: str = ",", decimal: str = "."
+) -> Union[str, List[str]]: ...
+def format_bytes(
+    value: Union[float, Numbers], binary: bool = True, decimals: int = 1
+) -> Union[str, List[str]]: ...
+def format_compact(value: Union[float, Numbers], decimals: int = 1) -> Union[str, List[str]]: ...
+def format_percent(value: Union[float, Numbers], decimals: int = 0) -> Union[str, List[str]]: ...
+def format_duration(seconds: Union[float, Numbers]) -> Union[str, List[str]]: ...
+def parse_number(
+    input: Union[str, List[str]], locale: str = "en"
+) -> Union[float, List[float]]: ...
+def parse_bytes(input: Union[str, List[str]]) -> Union[int, List[int]]: ...
+
+class RunningStats:
+    def __init__(self, compression: float = 100.0) -> None: ...
+    def push(self, value: float) -> None: ...
+    def extend(self, values: Union[Numbers, Iterable[float]]) -> None: ...
+    def merge(self, other: "RunningStats") -> None: ...
+    def quantile(self, q: float) -> Optional[float]: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def mean(self) -> Optional[float]: ...
+    @property
+    def variance(self) -> Optional[float]: ...
+    @property
+    def std(self) -> Optional[float]: ...
+    @property
+    def min(self) -> Optional[float]: ...
+    @property
+    def max(self) -> Optional[float]: ...
+    def __len__(self) -> int: ...
+
+# ─── Random ──────────────────────────────────────────────────────────────────
+
+def random(
+    lower: Optional[float] = None,
+    upper: Optional[float] = None,
+    floating: bool = False,
+    seed: Optional[int] = None,
+) -> Union[int, float]: ...
+def shuffle(list: List[Any], seed: Optional[int] = None) -> List[Any]: ...
+def sample(list: List[Any], seed: Optional[int] = None) -> Optional[Any]: ...
+def sample_size(list: List[Any], n: int = 1, seed: Optional[int] = None) -> List[Any]: ...
+def weighted_choice(list: List[Any], weights: Numbers, seed: Optional[int] = None) -> Optional[Any]: ...
+def reservoir_sample(iterable: Iterable[Any], k: int, seed: Optional[int] = None) -> List[Any]: ...
+
+class Random:
+    def __init__(self, seed: Optional[int] = None) -> None: ...
+    def random(
+        self, lower: Optional[float] = None, upper: Optional[float] = None, floating: bool = False
+    ) -> Union[int, float]: ...
+    def shuffle(self, list: List[Any]) -> List[Any]: ...
+    def sample(self, list: List[Any]) -> Optional[Any]: ...
+    def sample_size(self, list: List[Any], n: int = 1) -> List[Any]: ...
+    def weighted_choice(self, list: List[Any], weights: Numbers) -> Optional[Any]: ...
+    def reservoir_sample(self, iterable: Iterable[Any], k: int) -> List[Any]: ...
 
 # ─── Arrays ──────────────────────────────────────────────────────────────────
 
-def chunk(array: List[Any], size: int) -> List[List[Any]]: ...
+# A callable, a property path, a dict to match, or a (path, value) pair to match.
+# A [path, value] list also matches unless value is a str; a list of two strings
+# raises TypeError (use order_by to sort by several keys).
+Iteratee = Union[Callable[[Any], Any], str, Dict[Any, Any], List[Any], Tuple[str, Any]]
+
+# Omitting `fill` leaves the last chunk short; `fill=None` pads it with None.
+def chunk(
+    array: Iterable[Any], size: int, fill: Any = ..., strict: bool = False
+) -> List[List[Any]]: ...
+def windowed(
+    array: Iterable[Any], size: int, step: int = 1, partial: bool = False
+) -> List[List[Any]]: ...
+def pairwise(array: Iterable[Any]) -> List[List[Any]]: ...
+def split_at(array: Iterable[Any], idx: int) -> List[List[Any]]: ...
+def split_when(array: Iterable[Any], pred: Callable[[Any, Any], Any]) -> List[List[Any]]: ...
 def compact(array: List[Any]) -> List[Any]: ...
-def flatten_deep(array: List[List[Any]]) -> List[Any]: ...
-def unique(array: List[Any]) -> List[Any]: ...
-def group_by(array: List[Any], fn: Callable[[Any], Any]) -> Dict[Any, List[Any]]: ...
-def map(array: List[Any], fn: Callable[[Any], Any]) -> List[Any]: ...
-def filter(array: List[Any], fn: Callable[[Any], bool]) -> List[Any]: ...
-def find(array: List[Any], fn: Callable[[Any], bool]) -> Optional[Any]: ...
-def sort_by(array: List[Any], fn: Callable[[Any], Any]) -> List[Any]: ...
+def flatten(array: Iterable[Any], depth: int = 1, types: Optional[Any] = None) -> List[Any]: ...
+def flatten_deep(array: Iterable[Any], types: Optional[Any] = None) -> List[Any]: ...
+def unique(array: Iterable[Any], keep: str = "first") -> List[Any]: ...
+def unique_by(array: Iterable[Any], fn: Iteratee, keep: str = "first") -> List[Any]: ...
+def unique_with(
+    array: Iterable[Any], comparator: Callable[[Any, Any], Any], keep: str = "first"
+) -> List[Any]: ...
+def group_by(array: Iterable[Any], fn: Iteratee) -> Dict[Any, List[Any]]: ...
+def count_by(array: Iterable[Any], fn: Iteratee) -> Dict[Any, int]: ...
+def key_by(array: Iterable[Any], fn: Iteratee) -> Dict[Any, Any]: ...
+def partition(array: Iterable[Any], fn: Iteratee) -> List[List[Any]]: ...
+def aggregate(
+    array: Iterable[Any],
+    by: Optional[Union[Iteratee, List[Iteratee]]],
+    aggs: Dict[str, Tuple[Optional[Iteratee], str]],
+    as_records: bool = False,
+) -> Union[Dict[Any, Dict[str, Any]], List[Dict[str, Any]]]: ...
+def join(
+    left: Iterable[Dict[Any, Any]],
+    right: Iterable[Dict[Any, Any]],
+    on: Union[str, Tuple[Union[str, Callable[[Any], Any]], Union[str, Callable[[Any], Any]]]],
+    how: str = "inner",
+    suffixes: Tuple[str, str] = ("", "_right"),
+) -> List[Dict[Any, Any]]: ...
+def map(array: List[Any], fn: Iteratee) -> List[Any]: ...
+def filter(array: List[Any], fn: Iteratee) -> List[Any]: ...
+def find(array: List[Any], fn: Iteratee) -> Optional[Any]: ...
+# None and NaN keys go last; incomparable keys raise TypeError.
+def sort_by(array: Iterable[Any], fn: Iteratee) -> List[Any]: ...
+def order_by(
+    array: Iterable[Any],
+    keys: Union[Iteratee, List[Iteratee]],
+    orders: Optional[Union[str, List[str]]] = None,
+    nulls: str = "last",
+) -> List[Any]: ...
 def reduce(array: List[Any], fn: Callable[[Any, Any], Any], initial: Any) -> Any: ...
-def zip(a: List[Any], b: List[Any]) -> List[List[Any]]: ...
-def intersection(a: List[Any], b: List[Any]) -> List[Any]: ...
+def zip(*lists: Iterable[Any], as_tuples: bool = False) -> List[Union[List[Any], Tuple[Any, ...]]]: ...
+def zip_longest(
+    *lists: Iterable[Any], fill: Any = None, as_tuples: bool = False
+) -> List[Union[List[Any], Tuple[Any, ...]]]: ...
+def unzip(
+    rows: Iterable[Iterable[Any]], as_tuples: bool = False
+) -> List[Union[List[Any], Tuple[Any, ...]]]: ...
+def zip_with(fn: Callable[..., Any], *lists: Iterable[Any]) -> List[Any]: ...
+def zip_object(keys: Iterable[Any], values: Iterable[Any]) -> Dict[Any, Any]: ...
+def zip_object_deep(paths: Iterable[str], values: Iterable[Any]) -> Dict[str, Any]: ...
+def intersection(*lists: Iterable[Any]) -> List[Any]: ...
+def intersection_by(*args: Any) -> List[Any]: ...
+def intersection_with(*args: Any) -> List[Any]: ...
+def difference(*lists: Iterable[Any]) -> List[Any]: ...
+def difference_by(*args: Any) -> List[Any]: ...
+def difference_with(*args: Any) -> List[Any]: ...
+def union(*lists: Iterable[Any]) -> List[Any]: ...
+def union_by(*args: Any) -> List[Any]: ...
+def union_with(*args: Any) -> List[Any]: ...
+def xor(*lists: Iterable[Any]) -> List[Any]: ...
+def xor_by(*args: Any) -> List[Any]: ...
+def xor_with(*args: Any) -> List[Any]: ...
 
 # ─── Objects ─────────────────────────────────────────────────────────────────
 
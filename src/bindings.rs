@@ -2,8 +2,14 @@
 //! This module organizes all Python-facing functions by category.
 
 mod arrays;
+mod arrays_helpers;
+mod format;
+mod iteratee;
 mod numbers;
+mod numbers_helpers;
 mod objects;
+mod random;
+mod running_stats;
 mod strings;
 mod strings_helpers;
 
@@ -35,20 +41,116 @@ pub fn _rustdash(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(numbers::sum_by, m)?)?;
     m.add_function(wrap_pyfunction!(numbers::min_by, m)?)?;
     m.add_function(wrap_pyfunction!(numbers::max_by, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::mean_by, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::weighted_mean, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::geometric_mean, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::harmonic_mean, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::cumsum, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::cumprod, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::cummax, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::cummin, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::diff, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::pct_change, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::rolling, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::ewm_mean, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::covariance, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::correlation, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::linear_regression, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::histogram, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::digitize, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::bin_by, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::clamp, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::in_range, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::range, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::range_right, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::linspace, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::times, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::normalize, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::standardize, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::robust_scale, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::rescale, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::argmax, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::argmin, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::argmax_by, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::argmin_by, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::min_max, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::min_max_by, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::top_k, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::gcd, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::lcm, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::factorial, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::n_choose_k, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::mod_pow, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::is_prime, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::primes_up_to, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::interp, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::lerp, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::fill_gaps, m)?)?;
+    m.add_function(wrap_pyfunction!(numbers::resample, m)?)?;
+
+    // Register format functions
+    m.add_function(wrap_pyfunction!(format::format_number, m)?)?;
+    m.add_function(wrap_pyfunction!(format::format_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(format::format_compact, m)?)?;
+    m.add_function(wrap_pyfunction!(format::format_percent, m)?)?;
+    m.add_function(wrap_pyfunction!(format::format_duration, m)?)?;
+    m.add_function(wrap_pyfunction!(format::parse_number, m)?)?;
+    m.add_function(wrap_pyfunction!(format::parse_bytes, m)?)?;
+
+    // Register running statistics
+    m.add_class::<running_stats::PyRunningStats>()?;
+
+    // Register random functions
+    m.add_function(wrap_pyfunction!(random::random, m)?)?;
+    m.add_function(wrap_pyfunction!(random::shuffle, m)?)?;
+    m.add_function(wrap_pyfunction!(random::sample, m)?)?;
+    m.add_function(wrap_pyfunction!(random::sample_size, m)?)?;
+    m.add_function(wrap_pyfunction!(random::weighted_choice, m)?)?;
+    m.add_function(wrap_pyfunction!(random::reservoir_sample, m)?)?;
+    m.add_class::<random::PyRandom>()?;
 
     // Register arrays functions
     m.add_function(wrap_pyfunction!(arrays::chunk, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::windowed, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::pairwise, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::split_at, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::split_when, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::compact, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::flatten, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::flatten_deep, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::unique, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::unique_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::unique_with, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::group_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::count_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::key_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::partition, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::aggregate, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::join, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::map, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::filter, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::find, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::sort_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::order_by, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::reduce, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::zip, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::zip_longest, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::unzip, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::zip_with, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::zip_object, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::zip_object_deep, m)?)?;
     m.add_function(wrap_pyfunction!(arrays::intersection, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::intersection_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::intersection_with, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::difference, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::difference_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::difference_with, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::union, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::union_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::union_with, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::xor, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::xor_by, m)?)?;
+    m.add_function(wrap_pyfunction!(arrays::xor_with, m)?)?;
 
     // Register object functions
     m.add_function(wrap_pyfunction!(objects::get, m)?)?;
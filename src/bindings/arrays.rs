@@ -1,35 +1,589 @@
+use crate::bindings::arrays_helpers::{comparator_ids, group_into, key_ids, Collect, Interner};
+use crate::bindings::iteratee::Iteratee;
+use crate::bindings::objects::set_path;
+use crate::core::arrays::{
+    chunks_, difference_, intersection_, join_, order_by_, split_index_, union_, unique_, windows_,
+    xor_, AggFunc, AggNumber, GroupValues, JoinHow, Keep, NullsPosition, SortOrder,
+};
+use crate::core::objects::parse_path;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
-use std::collections::HashMap;
+use pyo3::types::{
+    PyBool, PyByteArray, PyBytes, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple,
+};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::ops::Range;
 
 // ─── Helpers ────────────────────────────────────────────────────────────────
 
-/// Recursively flatten nested lists into a flat Vec<PyObject>.
-fn flatten_recursive(obj: &Bound<'_, PyAny>, result: &mut Vec<PyObject>) -> PyResult<()> {
-    if let Ok(list) = obj.downcast::<PyList>() {
-        for item in list.iter() {
-            flatten_recursive(&item, result)?;
+/// Flatten nested containers up to `depth` levels, with an explicit stack of iterators
+/// so deep nesting cannot overflow the native stack.
+///
+/// Only lists are descended into unless `types` (a type or tuple of types, as for
+/// `isinstance`) says otherwise; `str`, `bytes` and `bytearray` are always atoms.
+/// The input itself may be any other iterable; an atom or a non-iterable input
+/// flattens to a one-element list.
+fn flatten_into<'py>(
+    py_input: &Bound<'py, PyAny>,
+    depth: usize,
+    types: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    let is_atom = |item: &Bound<'py, PyAny>| {
+        item.is_instance_of::<PyString>()
+            || item.is_instance_of::<PyBytes>()
+            || item.is_instance_of::<PyByteArray>()
+    };
+    let descend = |item: &Bound<'py, PyAny>| -> PyResult<bool> {
+        if is_atom(item) {
+            return Ok(false);
+        }
+        match types {
+            Some(types) => item.is_instance(types),
+            None => Ok(item.is_instance_of::<PyList>()),
+        }
+    };
+    if is_atom(py_input) {
+        return Ok(vec![py_input.clone()]);
+    }
+    let root = match py_input.try_iter() {
+        Ok(root) => root,
+        Err(err) if err.is_instance_of::<pyo3::exceptions::PyTypeError>(py_input.py()) => {
+            return Ok(vec![py_input.clone()]);
+        }
+        Err(err) => return Err(err),
+    };
+    let mut result = Vec::new();
+    // Containers being walked (kept alive by their iterators), to reject
+    // self-references instead of looping forever.
+    let mut open = vec![py_input.as_ptr() as usize];
+    let mut open_set: HashSet<usize> = open.iter().copied().collect();
+    let mut stack = vec![root];
+    while let Some(iter) = stack.last_mut() {
+        let Some(item) = iter.next() else {
+            stack.pop();
+            if let Some(container) = open.pop() {
+                open_set.remove(&container);
+            }
+            continue;
+        };
+        let item = item?;
+        if stack.len() <= depth && descend(&item)? {
+            let container = item.as_ptr() as usize;
+            if !open_set.insert(container) {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "cannot flatten a container that contains itself",
+                ));
+            }
+            stack.push(item.try_iter()?);
+            open.push(container);
+        } else {
+            result.push(item);
+        }
+    }
+    Ok(result)
+}
+
+/// The items of several input lists.
+type Lists<'py> = Vec<Vec<Bound<'py, PyAny>>>;
+
+/// Collect every positional argument (any iterable) into a list of items.
+fn collect_lists<'py>(lists: &[Bound<'py, PyAny>]) -> PyResult<Lists<'py>> {
+    lists
+        .iter()
+        .map(|list| list.try_iter()?.collect())
+        .collect()
+}
+
+/// Split lodash-style `*args` whose last element is the iteratee or comparator.
+fn split_last<'py>(
+    args: &Bound<'py, PyTuple>,
+    what: &str,
+) -> PyResult<(Lists<'py>, Bound<'py, PyAny>)> {
+    let args: Vec<Bound<'py, PyAny>> = args.iter().collect();
+    match args.split_last() {
+        Some((last, lists)) => Ok((collect_lists(lists)?, last.clone())),
+        None => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "expected lists followed by {what}"
+        ))),
+    }
+}
+
+/// Build the result list from the original items at the given `(list, index)` positions.
+fn pick_positions(
+    py: Python<'_>,
+    lists: &[Vec<Bound<'_, PyAny>>],
+    positions: Vec<(usize, usize)>,
+) -> PyObject {
+    let picked: Vec<&Bound<'_, PyAny>> = positions.into_iter().map(|(l, i)| &lists[l][i]).collect();
+    PyList::new_bound(py, picked).into()
+}
+
+/// Keep one element per id, choosing the first or last occurrence per `keep`.
+fn pick_unique(
+    py: Python<'_>,
+    items: &[Bound<'_, PyAny>],
+    ids: &[usize],
+    keep: &str,
+) -> PyResult<PyObject> {
+    let kept: Vec<&Bound<'_, PyAny>> = unique_(ids, keep.parse::<Keep>()?)
+        .into_iter()
+        .map(|i| &items[i])
+        .collect();
+    Ok(PyList::new_bound(py, kept).into())
+}
+
+/// `group_into` keyed by an iteratee over the items of `py_input`.
+fn keyed_dict(
+    py_input: &Bound<'_, PyAny>,
+    py_iteratee: Bound<'_, PyAny>,
+    collect: Collect,
+) -> PyResult<PyObject> {
+    let iteratee = Iteratee::new(py_iteratee)?;
+    let pairs = py_input.try_iter()?.map(|item| {
+        let item = item?;
+        Ok((iteratee.call(&item)?, item))
+    });
+    Ok(group_into(py_input.py(), pairs, collect)?.into())
+}
+
+/// One or several iteratees (a list or tuple holds one per key), with a name for each:
+/// the path for path strings, otherwise `key` (or `key0`, `key1`, ... for several).
+fn iteratee_list(keys: Bound<'_, PyAny>) -> PyResult<(Vec<String>, Vec<Iteratee<'_>>)> {
+    let keys: Vec<Bound<'_, PyAny>> =
+        if keys.is_instance_of::<PyList>() || keys.is_instance_of::<PyTuple>() {
+            keys.try_iter()?.collect::<PyResult<_>>()?
+        } else {
+            vec![keys]
+        };
+    let several = keys.len() > 1;
+    let names = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| match key.extract::<String>() {
+            Ok(path) => path,
+            Err(_) if several => format!("key{i}"),
+            Err(_) => "key".to_string(),
+        })
+        .collect();
+    let iteratees = keys
+        .into_iter()
+        .map(Iteratee::new)
+        .collect::<PyResult<_>>()?;
+    Ok((names, iteratees))
+}
+
+/// One `name: (field, agg)` entry of `aggregate`'s `aggs`.
+struct AggSpec<'py> {
+    name: Bound<'py, PyAny>,
+    field: Option<Iteratee<'py>>,
+    agg: AggFunc,
+}
+
+impl<'py> AggSpec<'py> {
+    fn new(name: Bound<'py, PyAny>, spec: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let (field, agg): (Option<Bound<'py, PyAny>>, String) = spec.extract().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "aggregate {name} must be a (field, agg) tuple"
+            ))
+        })?;
+        let agg: AggFunc = agg.parse()?;
+        if field.is_none() && agg.is_numeric() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "aggregate {name} needs a field for the numeric agg"
+            )));
+        }
+        Ok(AggSpec {
+            name,
+            field: field.map(Iteratee::new).transpose()?,
+            agg,
+        })
+    }
+
+    fn accumulator(&self) -> Accumulator<'py> {
+        match self.agg {
+            AggFunc::Count => Accumulator::Count(0),
+            AggFunc::First | AggFunc::Last => Accumulator::Pick(self.agg, None),
+            _ => Accumulator::Numbers(GroupValues::default()),
+        }
+    }
+}
+
+/// Running state of one aggregate within one group. `None` values are skipped.
+enum Accumulator<'py> {
+    Count(usize),
+    Pick(AggFunc, Option<Bound<'py, PyAny>>),
+    Numbers(GroupValues),
+}
+
+impl<'py> Accumulator<'py> {
+    fn push(&mut self, value: Bound<'py, PyAny>) -> PyResult<()> {
+        if value.is_none() {
+            return Ok(());
+        }
+        match self {
+            Accumulator::Count(n) => *n += 1,
+            Accumulator::Pick(AggFunc::First, Some(_)) => {}
+            Accumulator::Pick(_, picked) => *picked = Some(value),
+            // Ints stay exact up to 128 bits; bools are not numbers here.
+            Accumulator::Numbers(_) if value.is_instance_of::<PyBool>() => {
+                return Err(pyo3::exceptions::PyTypeError::new_err(
+                    "numeric aggregates do not accept bools",
+                ));
+            }
+            Accumulator::Numbers(values) if value.is_instance_of::<PyInt>() => {
+                values.push_int(value.extract()?)
+            }
+            Accumulator::Numbers(values) => values.push_float(value.extract()?),
+        }
+        Ok(())
+    }
+
+    fn finish(self, py: Python<'py>, agg: AggFunc) -> PyObject {
+        match self {
+            Accumulator::Count(n) => n.into_py(py),
+            Accumulator::Pick(_, picked) => picked.map_or_else(|| py.None(), Bound::unbind),
+            Accumulator::Numbers(values) => match values.aggregate(agg) {
+                Some(AggNumber::Int(i)) => i.into_py(py),
+                Some(AggNumber::Float(x)) => x.into_py(py),
+                None => py.None(),
+            },
+        }
+    }
+}
+
+/// The items of an iterable of dicts.
+fn records<'py>(py_input: &Bound<'py, PyAny>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    py_input
+        .try_iter()?
+        .map(|item| {
+            item?
+                .downcast_into::<PyDict>()
+                .map_err(|_| pyo3::exceptions::PyTypeError::new_err("join expects lists of dicts"))
+        })
+        .collect()
+}
+
+fn join_keys<'py>(
+    rows: &[Bound<'py, PyDict>],
+    on: Iteratee<'py>,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    rows.iter().map(|row| on.call(row.as_any())).collect()
+}
+
+/// The string fields present on both sides of a join, other than the shared join key.
+/// They get the matching suffix in every row, so the output fields do not depend on
+/// whether a row matched. Raises when a suffixed name would overwrite another field.
+fn join_collisions(
+    left: &[Bound<'_, PyDict>],
+    right: &[Bound<'_, PyDict>],
+    shared_key: Option<&str>,
+    (left_suffix, right_suffix): &(String, String),
+) -> PyResult<HashSet<String>> {
+    let field_names = |rows: &[Bound<'_, PyDict>]| {
+        let mut names = HashSet::new();
+        for row in rows {
+            for key in row.keys() {
+                if let Ok(name) = key.extract::<String>() {
+                    names.insert(name);
+                }
+            }
+        }
+        names
+    };
+    let left_fields = field_names(left);
+    let mut right_fields = field_names(right);
+    if let Some(shared) = shared_key {
+        right_fields.remove(shared);
+    }
+    let collisions: HashSet<String> = left_fields.intersection(&right_fields).cloned().collect();
+    let mut seen = HashSet::new();
+    for (fields, suffix) in [(left_fields, left_suffix), (right_fields, right_suffix)] {
+        for field in fields {
+            let name = match collisions.contains(&field) {
+                true => format!("{field}{suffix}"),
+                false => field,
+            };
+            if !seen.insert(name.clone()) {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "join field '{name}' would appear twice in the output; pick other suffixes"
+                )));
+            }
+        }
+    }
+    Ok(collisions)
+}
+
+/// Shallow-merge a joined pair of rows like `merge`, except that the `collisions`
+/// fields get the matching suffix on each side. The shared join key appears once.
+fn merge_records<'py>(
+    py: Python<'py>,
+    left: Option<&Bound<'py, PyDict>>,
+    right: Option<&Bound<'py, PyDict>>,
+    collisions: &HashSet<String>,
+    shared_key: Option<&str>,
+    (left_suffix, right_suffix): &(String, String),
+) -> PyResult<Bound<'py, PyDict>> {
+    let merged = PyDict::new_bound(py);
+    let renamed = |key: Bound<'py, PyAny>, suffix: &str| match key.extract::<&str>() {
+        Ok(name) if collisions.contains(name) => {
+            PyString::new_bound(py, &format!("{name}{suffix}")).into_any()
+        }
+        _ => key,
+    };
+    if let Some(left) = left {
+        for (key, value) in left.iter() {
+            merged.set_item(renamed(key, left_suffix), value)?;
+        }
+    }
+    if let Some(right) = right {
+        for (key, value) in right.iter() {
+            let shared =
+                shared_key.is_some_and(|shared| key.extract::<&str>().ok() == Some(shared));
+            if !(shared && left.is_some()) {
+                merged.set_item(renamed(key, right_suffix), value)?;
+            }
         }
-    } else {
-        result.push(obj.clone().unbind());
     }
-    Ok(())
+    Ok(merged)
+}
+
+/// Rows `0..len` taken across `lists`, as lists or tuples. Lists shorter than `len`
+/// are padded with `fill`.
+fn zip_rows(
+    py: Python<'_>,
+    lists: &[Vec<Bound<'_, PyAny>>],
+    len: usize,
+    fill: &Bound<'_, PyAny>,
+    as_tuples: bool,
+) -> PyObject {
+    let rows: Vec<PyObject> = (0..len)
+        .map(|i| {
+            let row = lists.iter().map(|list| list.get(i).unwrap_or(fill));
+            if as_tuples {
+                PyTuple::new_bound(py, row).into_any().unbind()
+            } else {
+                PyList::new_bound(py, row).into_any().unbind()
+            }
+        })
+        .collect();
+    PyList::new_bound(py, rows).into()
+}
+
+/// A sort key. Ints, floats and strings compare in Rust; anything else, and mixed
+/// types, go through Python's rich comparison.
+enum SortKey<'py> {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Obj(Bound<'py, PyAny>),
+}
+
+impl<'py> SortKey<'py> {
+    /// `None` for missing keys: Python `None` and float NaN.
+    fn new(key: Bound<'py, PyAny>) -> Option<Self> {
+        if key.is_none() {
+            return None;
+        }
+        if key.is_exact_instance_of::<PyInt>() {
+            if let Ok(i) = key.extract() {
+                return Some(SortKey::Int(i));
+            }
+        } else if key.is_exact_instance_of::<PyFloat>() {
+            let x: f64 = key.extract().ok()?;
+            return (!x.is_nan()).then_some(SortKey::Float(x));
+        } else if key.is_exact_instance_of::<PyString>() {
+            if let Ok(s) = key.extract() {
+                return Some(SortKey::Str(s));
+            }
+        }
+        Some(SortKey::Obj(key))
+    }
+
+    fn to_object(&self, py: Python<'py>) -> Bound<'py, PyAny> {
+        match self {
+            SortKey::Int(i) => i.into_py(py).into_bound(py),
+            SortKey::Float(x) => x.into_py(py).into_bound(py),
+            SortKey::Str(s) => PyString::new_bound(py, s).into_any(),
+            SortKey::Obj(obj) => obj.clone(),
+        }
+    }
+
+    fn compare(&self, other: &Self, py: Python<'py>) -> PyResult<Ordering> {
+        Ok(match (self, other) {
+            (SortKey::Int(a), SortKey::Int(b)) => a.cmp(b),
+            // NaN never gets here, and `-0.0 == 0.0` as in Python.
+            (SortKey::Float(a), SortKey::Float(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            (SortKey::Str(a), SortKey::Str(b)) => a.cmp(b),
+            _ => self.to_object(py).compare(other.to_object(py))?,
+        })
+    }
+}
+
+/// Sort the items of `py_input` by several iteratees, propagating comparison errors.
+fn order_items(
+    py_input: &Bound<'_, PyAny>,
+    iteratees: &[Iteratee<'_>],
+    orders: &[SortOrder],
+    nulls: NullsPosition,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let items: Vec<Bound<'_, PyAny>> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    let columns = iteratees
+        .iter()
+        .map(|iteratee| {
+            items
+                .iter()
+                .map(|item| Ok(SortKey::new(iteratee.call(item)?)))
+                .collect::<PyResult<Vec<_>>>()
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let order = order_by_(items.len(), &columns, orders, nulls, |a, b| {
+        a.compare(b, py)
+    })?;
+    let sorted: Vec<&Bound<'_, PyAny>> = order.into_iter().map(|i| &items[i]).collect();
+    Ok(PyList::new_bound(py, sorted).into())
+}
+
+type SetOp = fn(&[Vec<usize>]) -> Vec<(usize, usize)>;
+
+fn set_op(args: &Bound<'_, PyTuple>, op: SetOp) -> PyResult<PyObject> {
+    let lists = collect_lists(&args.iter().collect::<Vec<_>>())?;
+    let ids = key_ids(&lists)?;
+    Ok(pick_positions(args.py(), &lists, op(&ids)))
+}
+
+/// Compare elements by `iteratee(element)` (a callable or property path).
+fn set_op_by(args: &Bound<'_, PyTuple>, op: SetOp) -> PyResult<PyObject> {
+    let (lists, py_iteratee) = split_last(args, "an iteratee")?;
+    let iteratee = Iteratee::new(py_iteratee)?;
+    let keys = lists
+        .iter()
+        .map(|list| list.iter().map(|item| iteratee.call(item)).collect())
+        .collect::<PyResult<Vec<Vec<_>>>>()?;
+    let ids = key_ids(&keys)?;
+    Ok(pick_positions(args.py(), &lists, op(&ids)))
+}
+
+/// Compare elements with a `comparator(a, b)` callable instead of hash and `==`.
+fn set_op_with(args: &Bound<'_, PyTuple>, op: SetOp) -> PyResult<PyObject> {
+    let (lists, comparator) = split_last(args, "a comparator")?;
+    let ids = comparator_ids(&lists, &comparator)?;
+    Ok(pick_positions(args.py(), &lists, op(&ids)))
+}
+
+/// One list per range of `items`.
+fn slice_lists<'py>(
+    py: Python<'py>,
+    items: &[Bound<'py, PyAny>],
+    ranges: impl IntoIterator<Item = Range<usize>>,
+) -> PyObject {
+    let lists: Vec<_> = ranges
+        .into_iter()
+        .map(|range| PyList::new_bound(py, &items[range]))
+        .collect();
+    PyList::new_bound(py, lists).into()
+}
+
+/// A size or step from Python, where negative values are as invalid as zero.
+fn count_arg(value: isize) -> usize {
+    usize::try_from(value).unwrap_or(0)
 }
 
 // ─── Python wrappers ─────────────────────────────────────────────────────────
 
+/// `chunk`'s `fill`: once given it pads with any value, `None` included.
+pub enum Fill<'py> {
+    Missing,
+    Value(Bound<'py, PyAny>),
+}
+
+impl<'py> FromPyObject<'py> for Fill<'py> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(Fill::Value(ob.clone()))
+    }
+}
+
 #[pyfunction]
-pub fn chunk(py_input: Bound<'_, PyAny>, size: usize) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, size, fill = Fill::Missing, strict = false))]
+pub fn chunk(
+    py_input: Bound<'_, PyAny>,
+    size: isize,
+    fill: Fill<'_>,
+    strict: bool,
+) -> PyResult<PyObject> {
     let py = py_input.py();
-    let list = py_input.downcast::<PyList>()?;
-    let items: Vec<PyObject> = list.iter().map(|x| x.unbind()).collect();
+    let fill = match fill {
+        Fill::Missing => None,
+        Fill::Value(value) => Some(value),
+    };
+    if strict && fill.is_some() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "fill and strict cannot be used together",
+        ));
+    }
+    let size = count_arg(size);
+    let items: Vec<_> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    let chunks = PyList::empty_bound(py);
+    for range in chunks_(items.len(), size, strict)? {
+        let chunk = PyList::new_bound(py, &items[range]);
+        // Only the last chunk can be short. Python repeats the fill, so a size too
+        // large to allocate raises MemoryError.
+        if let Some(fill) = fill.as_ref().filter(|_| chunk.len() < size) {
+            let padding = PyList::new_bound(py, [fill])
+                .as_sequence()
+                .repeat(size - chunk.len())?;
+            chunk.as_sequence().in_place_concat(&padding)?;
+        }
+        chunks.append(chunk)?;
+    }
+    Ok(chunks.into())
+}
 
-    let result: Vec<PyObject> = items
-        .chunks(size)
-        .map(|c| PyList::new_bound(py, c).into())
-        .collect();
+#[pyfunction]
+#[pyo3(signature = (py_input, size, step = 1, partial = false))]
+pub fn windowed(
+    py_input: Bound<'_, PyAny>,
+    size: isize,
+    step: isize,
+    partial: bool,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let items: Vec<_> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    let ranges = windows_(items.len(), count_arg(size), count_arg(step), partial)?;
+    Ok(slice_lists(py, &items, ranges))
+}
 
-    Ok(PyList::new_bound(py, &result).into())
+#[pyfunction]
+pub fn pairwise(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let items: Vec<_> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    Ok(slice_lists(py, &items, windows_(items.len(), 2, 1, false)?))
+}
+
+#[pyfunction]
+pub fn split_at(py_input: Bound<'_, PyAny>, idx: isize) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let items: Vec<_> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    let mid = split_index_(items.len(), idx);
+    Ok(slice_lists(py, &items, [0..mid, mid..items.len()]))
+}
+
+#[pyfunction]
+pub fn split_when(py_input: Bound<'_, PyAny>, pred: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let items: Vec<_> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for i in 1..items.len() {
+        if pred.call1((&items[i - 1], &items[i]))?.is_truthy()? {
+            ranges.push(start..i);
+            start = i;
+        }
+    }
+    if !items.is_empty() {
+        ranges.push(start..items.len());
+    }
+    Ok(slice_lists(py, &items, ranges))
 }
 
 #[pyfunction]
@@ -45,94 +599,267 @@ pub fn compact(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
 }
 
 #[pyfunction]
-pub fn flatten_deep(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
-    let py = py_input.py();
-    let mut result = Vec::new();
-    flatten_recursive(&py_input, &mut result)?;
-    Ok(PyList::new_bound(py, &result).into())
+#[pyo3(signature = (py_input, depth = 1, types = None))]
+pub fn flatten(
+    py_input: Bound<'_, PyAny>,
+    depth: usize,
+    types: Option<Bound<'_, PyAny>>,
+) -> PyResult<PyObject> {
+    let flat = flatten_into(&py_input, depth, types.as_ref())?;
+    Ok(PyList::new_bound(py_input.py(), flat).into())
 }
 
 #[pyfunction]
-pub fn unique(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
-    let py = py_input.py();
+#[pyo3(signature = (py_input, types = None))]
+pub fn flatten_deep(
+    py_input: Bound<'_, PyAny>,
+    types: Option<Bound<'_, PyAny>>,
+) -> PyResult<PyObject> {
+    let flat = flatten_into(&py_input, usize::MAX, types.as_ref())?;
+    Ok(PyList::new_bound(py_input.py(), flat).into())
+}
 
-    // Fast path: integer list
-    if let Ok(ints) = py_input.extract::<Vec<i64>>() {
-        let mut seen = std::collections::HashSet::new();
-        let result: Vec<i64> = ints.into_iter().filter(|x| seen.insert(*x)).collect();
-        return Ok(PyList::new_bound(py, &result).into());
-    }
+#[pyfunction]
+#[pyo3(signature = (py_input, keep = "first"))]
+pub fn unique(py_input: Bound<'_, PyAny>, keep: &str) -> PyResult<PyObject> {
+    let items: Vec<Bound<'_, PyAny>> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    let ids = key_ids(std::slice::from_ref(&items))?;
+    pick_unique(py_input.py(), &items, &ids[0], keep)
+}
 
-    // Fast path: string list
-    if let Ok(strings) = py_input.extract::<Vec<String>>() {
-        let mut seen = std::collections::HashSet::new();
-        let result: Vec<String> = strings
-            .into_iter()
-            .filter(|x| seen.insert(x.clone()))
-            .collect();
-        return Ok(PyList::new_bound(py, &result).into());
-    }
+#[pyfunction]
+#[pyo3(signature = (py_input, py_iteratee, keep = "first"))]
+pub fn unique_by(
+    py_input: Bound<'_, PyAny>,
+    py_iteratee: Bound<'_, PyAny>,
+    keep: &str,
+) -> PyResult<PyObject> {
+    let iteratee = Iteratee::new(py_iteratee)?;
+    let items: Vec<Bound<'_, PyAny>> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    let keys = items
+        .iter()
+        .map(|item| iteratee.call(item))
+        .collect::<PyResult<Vec<_>>>()?;
+    let ids = key_ids(&[keys])?;
+    pick_unique(py_input.py(), &items, &ids[0], keep)
+}
 
-    // Fallback: generic Python objects using hash/eq
-    let list = py_input.downcast::<PyList>()?;
-    let mut buckets: HashMap<isize, Vec<PyObject>> = HashMap::new();
-    let mut result: Vec<PyObject> = Vec::new();
+#[pyfunction]
+#[pyo3(signature = (py_input, comparator, keep = "first"))]
+pub fn unique_with(
+    py_input: Bound<'_, PyAny>,
+    comparator: Bound<'_, PyAny>,
+    keep: &str,
+) -> PyResult<PyObject> {
+    let items: Vec<Bound<'_, PyAny>> = py_input.try_iter()?.collect::<PyResult<_>>()?;
+    let ids = comparator_ids(std::slice::from_ref(&items), &comparator)?;
+    pick_unique(py_input.py(), &items, &ids[0], keep)
+}
 
-    for item in list.iter() {
-        let hash = item.hash()?;
-        let bucket = buckets.entry(hash).or_default();
-        let is_dup = bucket
-            .iter()
-            .any(|existing| item.eq(existing.bind(py)).unwrap_or(false));
-        if !is_dup {
-            bucket.push(item.clone().unbind());
-            result.push(item.unbind());
+#[pyfunction]
+pub fn group_by(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    keyed_dict(&py_input, py_iteratee, Collect::All)
+}
+
+#[pyfunction]
+pub fn count_by(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    keyed_dict(&py_input, py_iteratee, Collect::Count)
+}
+
+#[pyfunction]
+pub fn key_by(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    keyed_dict(&py_input, py_iteratee, Collect::Last)
+}
+
+#[pyfunction]
+pub fn partition(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let iteratee = Iteratee::new(py_iteratee)?;
+    let (mut matches, mut rest) = (Vec::new(), Vec::new());
+    for item in py_input.try_iter()? {
+        let item = item?;
+        if iteratee.test(&item)? {
+            matches.push(item);
+        } else {
+            rest.push(item);
         }
     }
-
-    Ok(PyList::new_bound(py, &result).into())
+    let parts = [PyList::new_bound(py, matches), PyList::new_bound(py, rest)];
+    Ok(PyList::new_bound(py, parts).into())
 }
 
 #[pyfunction]
-pub fn group_by(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, by, aggs, as_records = false))]
+pub fn aggregate(
+    py_input: Bound<'_, PyAny>,
+    by: Option<Bound<'_, PyAny>>,
+    aggs: Bound<'_, PyDict>,
+    as_records: bool,
+) -> PyResult<PyObject> {
     let py = py_input.py();
-    let list = py_input.downcast::<PyList>()?;
-    let result = PyDict::new_bound(py);
+    let (key_names, by) = match by {
+        None => (Vec::new(), Vec::new()),
+        Some(by) => iteratee_list(by)?,
+    };
+    let specs = aggs
+        .iter()
+        .map(|(name, spec)| AggSpec::new(name, &spec))
+        .collect::<PyResult<Vec<_>>>()?;
+    if as_records {
+        for spec in &specs {
+            if let Ok(name) = spec.name.extract::<String>() {
+                if key_names.contains(&name) {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "aggregate '{name}' clashes with a key field of the same name"
+                    )));
+                }
+            }
+        }
+    }
 
-    for item in list.iter() {
-        let key = py_func.call1((&item,))?;
-        if let Ok(Some(existing)) = result.get_item(&key) {
-            let existing_list = existing.downcast::<PyList>()?;
-            existing_list.append(&item)?;
-        } else {
-            let new_list = PyList::new_bound(py, &[item.unbind()]);
-            result.set_item(&key, new_list)?;
+    // One pass over the items: resolve the group, then feed every aggregate.
+    let mut interner = Interner::default();
+    let mut groups: Vec<(Bound<'_, PyAny>, Vec<Accumulator<'_>>)> = Vec::new();
+    for item in py_input.try_iter()? {
+        let item = item?;
+        let keys = by
+            .iter()
+            .map(|iteratee| iteratee.call(&item))
+            .collect::<PyResult<Vec<_>>>()?;
+        let key = match keys.as_slice() {
+            [] => py.None().into_bound(py),
+            [key] => key.clone(),
+            _ => PyTuple::new_bound(py, &keys).into_any(),
+        };
+        let id = interner.id(&key)?;
+        if id == groups.len() {
+            let accumulators = specs.iter().map(|spec| spec.accumulator()).collect();
+            groups.push((key, accumulators));
+        }
+        for (spec, accumulator) in specs.iter().zip(&mut groups[id].1) {
+            let value = match &spec.field {
+                Some(field) => field.call(&item)?,
+                None => item.clone(),
+            };
+            accumulator.push(value)?;
         }
     }
 
-    Ok(result.into())
+    let mut rows = Vec::with_capacity(groups.len());
+    for (key, accumulators) in groups {
+        let row = PyDict::new_bound(py);
+        for (spec, accumulator) in specs.iter().zip(accumulators) {
+            row.set_item(&spec.name, accumulator.finish(py, spec.agg))?;
+        }
+        rows.push((key, row));
+    }
+    if !as_records {
+        let result = PyDict::new_bound(py);
+        for (key, row) in rows {
+            result.set_item(key, row)?;
+        }
+        return Ok(result.into());
+    }
+    let mut records = Vec::with_capacity(rows.len());
+    for (key, row) in rows {
+        let record = PyDict::new_bound(py);
+        match key_names.as_slice() {
+            [] => {}
+            [name] => record.set_item(name, key)?,
+            names => {
+                for (name, part) in names.iter().zip(key.try_iter()?) {
+                    record.set_item(name, part?)?;
+                }
+            }
+        }
+        record.update(row.as_mapping())?;
+        records.push(record);
+    }
+    Ok(PyList::new_bound(py, records).into())
+}
+
+#[pyfunction]
+#[pyo3(signature = (left, right, on, how = "inner", suffixes = (String::new(), "_right".to_string())))]
+pub fn join(
+    left: Bound<'_, PyAny>,
+    right: Bound<'_, PyAny>,
+    on: Bound<'_, PyAny>,
+    how: &str,
+    suffixes: (String, String),
+) -> PyResult<PyObject> {
+    let py = left.py();
+    let how: JoinHow = how.parse()?;
+    if suffixes.0 == suffixes.1 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "suffixes must differ",
+        ));
+    }
+    // `on="id"` names the same field on both sides, which then appears once in the output.
+    let (left_on, right_on, shared_key) = match on.extract::<String>() {
+        Ok(path) => {
+            let shared = (!path.contains(['.', '['])).then(|| path.clone());
+            (on.clone(), on, shared)
+        }
+        Err(_) => {
+            let (left_on, right_on) = on.extract().map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "on must be a key path or a (left_key, right_key) pair",
+                )
+            })?;
+            (left_on, right_on, None)
+        }
+    };
+    let left = records(&left)?;
+    let right = records(&right)?;
+    let left_keys = join_keys(&left, Iteratee::new(left_on)?)?;
+    let right_keys = join_keys(&right, Iteratee::new(right_on)?)?;
+    let ids = key_ids(&[left_keys.clone(), right_keys.clone()])?;
+    // Null keys never match.
+    let mask = |keys: &[Bound<'_, PyAny>], ids: &[usize]| -> Vec<Option<usize>> {
+        keys.iter()
+            .zip(ids)
+            .map(|(key, &id)| (!key.is_none()).then_some(id))
+            .collect()
+    };
+    let pairs = join_(&mask(&left_keys, &ids[0]), &mask(&right_keys, &ids[1]), how);
+    let collisions = join_collisions(&left, &right, shared_key.as_deref(), &suffixes)?;
+    let rows = pairs
+        .into_iter()
+        .map(|(l, r)| {
+            merge_records(
+                py,
+                l.map(|l| &left[l]),
+                r.map(|r| &right[r]),
+                &collisions,
+                shared_key.as_deref(),
+                &suffixes,
+            )
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(PyList::new_bound(py, rows).into())
 }
 
 #[pyfunction]
-pub fn map(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<PyObject> {
+pub fn map(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
     let py = py_input.py();
+    let iteratee = Iteratee::new(py_iteratee)?;
     let list = py_input.downcast::<PyList>()?;
     let mut result = Vec::with_capacity(list.len());
     for item in list.iter() {
-        let mapped = py_func.call1((&item,))?;
+        let mapped = iteratee.call(&item)?;
         result.push(mapped.unbind());
     }
     Ok(PyList::new_bound(py, &result).into())
 }
 
 #[pyfunction]
-pub fn filter(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<PyObject> {
+pub fn filter(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
     let py = py_input.py();
+    let iteratee = Iteratee::new(py_iteratee)?;
     let list = py_input.downcast::<PyList>()?;
     let mut result = Vec::new();
     for item in list.iter() {
-        let keep: bool = py_func.call1((&item,))?.extract()?;
-        if keep {
+        if iteratee.test(&item)? {
             result.push(item.unbind());
         }
     }
@@ -140,12 +867,12 @@ pub fn filter(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult
 }
 
 #[pyfunction]
-pub fn find(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<PyObject> {
+pub fn find(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
     let py = py_input.py();
+    let iteratee = Iteratee::new(py_iteratee)?;
     let list = py_input.downcast::<PyList>()?;
     for item in list.iter() {
-        let matches: bool = py_func.call1((&item,))?.extract()?;
-        if matches {
+        if iteratee.test(&item)? {
             return Ok(item.unbind());
         }
     }
@@ -153,24 +880,48 @@ pub fn find(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<P
 }
 
 #[pyfunction]
-pub fn sort_by(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<PyObject> {
-    let py = py_input.py();
-    let list = py_input.downcast::<PyList>()?;
+pub fn sort_by(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let iteratee = Iteratee::new(py_iteratee)?;
+    // Unlike the old comparator, which treated incomparable keys as equal, missing keys
+    // go last and incomparable ones raise.
+    order_items(&py_input, &[iteratee], &[], NullsPosition::Last)
+}
 
-    let mut items: Vec<(PyObject, PyObject)> = Vec::with_capacity(list.len());
-    for item in list.iter() {
-        let key = py_func.call1((&item,))?;
-        items.push((item.unbind(), key.unbind()));
+#[pyfunction]
+#[pyo3(signature = (py_input, keys, orders = None, nulls = "last"))]
+pub fn order_by(
+    py_input: Bound<'_, PyAny>,
+    keys: Bound<'_, PyAny>,
+    orders: Option<Bound<'_, PyAny>>,
+    nulls: &str,
+) -> PyResult<PyObject> {
+    // A list or tuple holds one iteratee per key; anything else is a single key.
+    let iteratees = if keys.is_instance_of::<PyList>() || keys.is_instance_of::<PyTuple>() {
+        keys.try_iter()?
+            .map(|key| Iteratee::new(key?))
+            .collect::<PyResult<Vec<_>>>()?
+    } else {
+        vec![Iteratee::new(keys)?]
+    };
+    let orders = match orders {
+        None => Vec::new(),
+        Some(orders) => match orders.extract::<String>() {
+            Ok(order) => vec![order.parse::<SortOrder>()?; iteratees.len()],
+            Err(_) => orders
+                .extract::<Vec<String>>()?
+                .iter()
+                .map(|order| order.parse::<SortOrder>())
+                .collect::<Result<_, _>>()?,
+        },
+    };
+    if orders.len() > iteratees.len() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "got {} orders for {} keys",
+            orders.len(),
+            iteratees.len()
+        )));
     }
-
-    items.sort_by(|a, b| {
-        let a_key = a.1.bind(py);
-        let b_key = b.1.bind(py);
-        a_key.compare(b_key).unwrap_or(std::cmp::Ordering::Equal)
-    });
-
-    let result: Vec<PyObject> = items.into_iter().map(|(item, _)| item).collect();
-    Ok(PyList::new_bound(py, &result).into())
+    order_items(&py_input, &iteratees, &orders, nulls.parse()?)
 }
 
 #[pyfunction]
@@ -189,78 +940,162 @@ pub fn reduce(
 }
 
 #[pyfunction]
-pub fn zip(py_a: Bound<'_, PyAny>, py_b: Bound<'_, PyAny>) -> PyResult<PyObject> {
-    let py = py_a.py();
-    let list_a = py_a.downcast::<PyList>()?;
-    let list_b = py_b.downcast::<PyList>()?;
+#[pyo3(signature = (*lists, as_tuples = false))]
+pub fn zip(lists: Bound<'_, PyTuple>, as_tuples: bool) -> PyResult<PyObject> {
+    let py = lists.py();
+    let lists = collect_lists(&lists.iter().collect::<Vec<_>>())?;
+    let len = lists.iter().map(Vec::len).min().unwrap_or(0);
+    Ok(zip_rows(
+        py,
+        &lists,
+        len,
+        &py.None().into_bound(py),
+        as_tuples,
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (*lists, fill = None, as_tuples = false))]
+pub fn zip_longest(
+    lists: Bound<'_, PyTuple>,
+    fill: Option<Bound<'_, PyAny>>,
+    as_tuples: bool,
+) -> PyResult<PyObject> {
+    let py = lists.py();
+    let lists = collect_lists(&lists.iter().collect::<Vec<_>>())?;
+    let len = lists.iter().map(Vec::len).max().unwrap_or(0);
+    let fill = fill.unwrap_or_else(|| py.None().into_bound(py));
+    Ok(zip_rows(py, &lists, len, &fill, as_tuples))
+}
 
-    let len = std::cmp::min(list_a.len(), list_b.len());
+/// Inverse of `zip`: regroup rows into columns, truncated to the shortest row.
+#[pyfunction]
+#[pyo3(signature = (rows, as_tuples = false))]
+pub fn unzip(rows: Bound<'_, PyAny>, as_tuples: bool) -> PyResult<PyObject> {
+    let py = rows.py();
+    let rows = collect_lists(&rows.try_iter()?.collect::<PyResult<Vec<_>>>()?)?;
+    let len = rows.iter().map(Vec::len).min().unwrap_or(0);
+    Ok(zip_rows(
+        py,
+        &rows,
+        len,
+        &py.None().into_bound(py),
+        as_tuples,
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (func, *lists))]
+pub fn zip_with(func: Bound<'_, PyAny>, lists: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    let py = lists.py();
+    let lists = collect_lists(&lists.iter().collect::<Vec<_>>())?;
+    let len = lists.iter().map(Vec::len).min().unwrap_or(0);
     let mut result = Vec::with_capacity(len);
     for i in 0..len {
-        let a = list_a.get_item(i)?;
-        let b = list_b.get_item(i)?;
-        let pair = PyList::new_bound(py, &[a.unbind(), b.unbind()]);
-        result.push(pair.into_any().unbind());
+        let args = PyTuple::new_bound(py, lists.iter().map(|list| &list[i]));
+        result.push(func.call1(args)?);
     }
-    Ok(PyList::new_bound(py, &result).into())
+    Ok(PyList::new_bound(py, result).into())
 }
 
+/// Dict from parallel keys and values; keys without a value map to `None`.
 #[pyfunction]
-pub fn intersection(py_a: Bound<'_, PyAny>, py_b: Bound<'_, PyAny>) -> PyResult<PyObject> {
-    let py = py_a.py();
+pub fn zip_object(keys: Bound<'_, PyAny>, values: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = keys.py();
+    let result = PyDict::new_bound(py);
+    let mut values = values.try_iter()?;
+    for key in keys.try_iter()? {
+        let value = values.next().transpose()?;
+        result.set_item(key?, value)?;
+    }
+    Ok(result.into())
+}
 
-    // Fast path: integers
-    if let (Ok(a), Ok(b)) = (py_a.extract::<Vec<i64>>(), py_b.extract::<Vec<i64>>()) {
-        let set_b: std::collections::HashSet<i64> = b.into_iter().collect();
-        let mut seen = std::collections::HashSet::new();
-        let result: Vec<i64> = a
-            .into_iter()
-            .filter(|x| set_b.contains(x) && seen.insert(*x))
-            .collect();
-        return Ok(PyList::new_bound(py, &result).into());
+/// Like `zip_object`, but each key is a property path building nested dicts and lists.
+#[pyfunction]
+pub fn zip_object_deep(paths: Bound<'_, PyAny>, values: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = paths.py();
+    let result = PyDict::new_bound(py);
+    let mut values = values.try_iter()?;
+    for path in paths.try_iter()? {
+        let path: String = path?.extract()?;
+        let value = match values.next().transpose()? {
+            Some(value) => value,
+            None => py.None().into_bound(py),
+        };
+        set_path(result.as_any(), &parse_path(&path), &value)?;
     }
+    Ok(result.into())
+}
 
-    // Fallback: generic using Python hash/eq
-    let list_a = py_a.downcast::<PyList>()?;
-    let list_b = py_b.downcast::<PyList>()?;
+#[pyfunction]
+#[pyo3(signature = (*lists))]
+pub fn intersection(lists: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op(&lists, intersection_)
+}
 
-    // Build lookup from b
-    let mut b_buckets: HashMap<isize, Vec<PyObject>> = HashMap::new();
-    for item in list_b.iter() {
-        let hash = item.hash()?;
-        b_buckets.entry(hash).or_default().push(item.unbind());
-    }
+#[pyfunction]
+#[pyo3(signature = (*args))]
+pub fn intersection_by(args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op_by(&args, intersection_)
+}
 
-    let mut seen: HashMap<isize, Vec<PyObject>> = HashMap::new();
-    let mut result: Vec<PyObject> = Vec::new();
+#[pyfunction]
+#[pyo3(signature = (*args))]
+pub fn intersection_with(args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op_with(&args, intersection_)
+}
 
-    for item in list_a.iter() {
-        let hash = item.hash()?;
+#[pyfunction]
+#[pyo3(signature = (*lists))]
+pub fn difference(lists: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op(&lists, difference_)
+}
 
-        // Check if in b
-        let in_b = b_buckets
-            .get(&hash)
-            .map(|bucket| {
-                bucket
-                    .iter()
-                    .any(|b_item| item.eq(b_item.bind(py)).unwrap_or(false))
-            })
-            .unwrap_or(false);
+#[pyfunction]
+#[pyo3(signature = (*args))]
+pub fn difference_by(args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op_by(&args, difference_)
+}
 
-        if !in_b {
-            continue;
-        }
+#[pyfunction]
+#[pyo3(signature = (*args))]
+pub fn difference_with(args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op_with(&args, difference_)
+}
 
-        // Dedup
-        let seen_bucket = seen.entry(hash).or_default();
-        let is_dup = seen_bucket
-            .iter()
-            .any(|existing| item.eq(existing.bind(py)).unwrap_or(false));
-        if !is_dup {
-            seen_bucket.push(item.clone().unbind());
-            result.push(item.unbind());
-        }
-    }
+#[pyfunction]
+#[pyo3(signature = (*lists))]
+pub fn union(lists: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op(&lists, union_)
+}
 
-    Ok(PyList::new_bound(py, &result).into())
+#[pyfunction]
+#[pyo3(signature = (*args))]
+pub fn union_by(args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op_by(&args, union_)
+}
+
+#[pyfunction]
+#[pyo3(signature = (*args))]
+pub fn union_with(args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op_with(&args, union_)
+}
+
+#[pyfunction]
+#[pyo3(signature = (*lists))]
+pub fn xor(lists: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op(&lists, xor_)
+}
+
+#[pyfunction]
+#[pyo3(signature = (*args))]
+pub fn xor_by(args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op_by(&args, xor_)
+}
+
+#[pyfunction]
+#[pyo3(signature = (*args))]
+pub fn xor_with(args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    set_op_with(&args, xor_)
 }
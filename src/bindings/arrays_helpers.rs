@@ -0,0 +1,206 @@
+use crate::core::arrays::ArraysError;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PySet, PyString, PyTuple};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+impl From<ArraysError> for PyErr {
+    fn from(err: ArraysError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Nesting depth past which `deep_hash` stops descending; deeper levels all share one
+/// hash and are told apart by `==` alone (which also reports self-referencing containers).
+const MAX_HASH_DEPTH: usize = 64;
+
+fn combine(h: isize, x: isize) -> isize {
+    h.wrapping_mul(1_000_003) ^ x
+}
+
+/// `hash()` for hashable objects. Unhashable dicts, lists, tuples and sets are hashed by
+/// content instead, so structurally equal values land in the same bucket.
+fn deep_hash(key: &Bound<'_, PyAny>, depth: usize) -> PyResult<isize> {
+    let err = match key.hash() {
+        Ok(hash) => return Ok(hash),
+        Err(err) if err.is_instance_of::<PyTypeError>(key.py()) => err,
+        Err(err) => return Err(err),
+    };
+    if depth >= MAX_HASH_DEPTH {
+        return Ok(0);
+    }
+    if let Ok(dict) = key.downcast::<PyDict>() {
+        // Dict equality ignores insertion order, so entries are summed.
+        let mut hash: isize = 0x2d1c7;
+        for (k, v) in dict.iter() {
+            hash = hash.wrapping_add(combine(k.hash()?, deep_hash(&v, depth + 1)?));
+        }
+        return Ok(hash);
+    }
+    if key.is_instance_of::<PyList>() || key.is_instance_of::<PyTuple>() {
+        let mut hash: isize = 0x345678;
+        for item in key.try_iter()? {
+            hash = combine(hash, deep_hash(&item?, depth + 1)?);
+        }
+        return Ok(hash);
+    }
+    if key.is_instance_of::<PySet>() || key.is_instance_of::<PyFrozenSet>() {
+        let mut hash: isize = 0x5e7;
+        for item in key.try_iter()? {
+            hash = hash.wrapping_add(item?.hash()?);
+        }
+        return Ok(hash);
+    }
+    Err(err)
+}
+
+/// Hands out one id per distinct Python key: keys are bucketed by `hash()` (or
+/// `deep_hash` for unhashable containers), and `==` resolves collisions within a bucket.
+#[derive(Default)]
+pub struct Interner<'py> {
+    buckets: HashMap<isize, Vec<(Bound<'py, PyAny>, usize)>>,
+    len: usize,
+}
+
+impl<'py> Interner<'py> {
+    pub fn id(&mut self, key: &Bound<'py, PyAny>) -> PyResult<usize> {
+        let bucket = self.buckets.entry(deep_hash(key, 0)?).or_default();
+        for (existing, id) in bucket.iter() {
+            if key.eq(existing)? {
+                return Ok(*id);
+            }
+        }
+        let id = self.len;
+        self.len += 1;
+        bucket.push((key.clone(), id));
+        Ok(id)
+    }
+}
+
+fn ids_by<K: Hash + Eq>(lists: Vec<Vec<K>>) -> Vec<Vec<usize>> {
+    let mut ids = HashMap::new();
+    lists
+        .into_iter()
+        .map(|list| {
+            list.into_iter()
+                .map(|k| {
+                    let next = ids.len();
+                    *ids.entry(k).or_insert(next)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Extract every key as `T` when all of them are exactly of Python type `P`.
+fn extract_all<'py, P, T>(lists: &[Vec<Bound<'py, PyAny>>]) -> Option<Vec<Vec<T>>>
+where
+    P: pyo3::PyTypeInfo,
+    T: for<'a> FromPyObject<'a>,
+{
+    if !lists
+        .iter()
+        .flatten()
+        .all(|k| k.is_exact_instance_of::<P>())
+    {
+        return None;
+    }
+    lists
+        .iter()
+        .map(|list| list.iter().map(|k| k.extract().ok()).collect())
+        .collect()
+}
+
+/// Map each key to an id shared by all equal keys, so set logic runs on plain integers.
+///
+/// Lists made only of ints, only of strings or only of (non-NaN) floats are compared in
+/// Rust; anything else goes through an `Interner`.
+pub fn key_ids(lists: &[Vec<Bound<'_, PyAny>>]) -> PyResult<Vec<Vec<usize>>> {
+    if let Some(ints) = extract_all::<PyInt, i64>(lists) {
+        return Ok(ids_by(ints));
+    }
+    if let Some(strings) = extract_all::<PyString, String>(lists) {
+        return Ok(ids_by(strings));
+    }
+    if let Some(floats) = extract_all::<PyFloat, f64>(lists) {
+        if !floats.iter().flatten().any(|x| x.is_nan()) {
+            // `0.0 == -0.0` in Python, so both map to the same bits.
+            let bits = floats
+                .into_iter()
+                .map(|list| list.into_iter().map(|x| (x + 0.0).to_bits()).collect())
+                .collect();
+            return Ok(ids_by(bits));
+        }
+    }
+    let mut interner = Interner::default();
+    lists
+        .iter()
+        .map(|list| list.iter().map(|k| interner.id(k)).collect())
+        .collect()
+}
+
+/// Like `key_ids`, but two keys share an id when `comparator(key, representative)` is
+/// truthy for the first key seen of that id.
+pub fn comparator_ids(
+    lists: &[Vec<Bound<'_, PyAny>>],
+    comparator: &Bound<'_, PyAny>,
+) -> PyResult<Vec<Vec<usize>>> {
+    let mut representatives: Vec<&Bound<'_, PyAny>> = Vec::new();
+    let mut ids = Vec::with_capacity(lists.len());
+    for list in lists {
+        let mut list_ids = Vec::with_capacity(list.len());
+        for key in list {
+            let mut found = None;
+            for (id, rep) in representatives.iter().enumerate() {
+                if comparator.call1((key, *rep))?.is_truthy()? {
+                    found = Some(id);
+                    break;
+                }
+            }
+            list_ids.push(match found {
+                Some(id) => id,
+                None => {
+                    representatives.push(key);
+                    representatives.len() - 1
+                }
+            });
+        }
+        ids.push(list_ids);
+    }
+    Ok(ids)
+}
+
+/// What `group_into` keeps for each key.
+#[derive(Clone, Copy)]
+pub enum Collect {
+    /// Every item, in a list.
+    All,
+    /// The number of items.
+    Count,
+    /// The last item.
+    Last,
+}
+
+/// Build a dict from `(key, item)` pairs, keeping per key what `collect` asks for.
+pub fn group_into<'py>(
+    py: Python<'py>,
+    pairs: impl IntoIterator<Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
+    collect: Collect,
+) -> PyResult<Bound<'py, PyDict>> {
+    let result = PyDict::new_bound(py);
+    for pair in pairs {
+        let (key, item) = pair?;
+        let existing = result.get_item(&key)?;
+        match (collect, existing) {
+            (Collect::All, Some(existing)) => existing.downcast::<PyList>()?.append(&item)?,
+            (Collect::All, None) => result.set_item(&key, PyList::new_bound(py, [item]))?,
+            (Collect::Count, Some(existing)) => {
+                result.set_item(&key, existing.extract::<usize>()? + 1)?
+            }
+            (Collect::Count, None) => result.set_item(&key, 1)?,
+            (Collect::Last, _) => result.set_item(&key, item)?,
+        }
+    }
+    Ok(result)
+}
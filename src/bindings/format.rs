@@ -0,0 +1,109 @@
+use crate::bindings::numbers_helpers::{with_floats, with_numbers, Numbers};
+use crate::core::format::{
+    format_bytes_, format_compact_, format_duration_, format_integer_, format_number_,
+    format_percent_, parse_bytes_, parse_number_, NumberLocale,
+};
+use crate::core::numbers::NumbersError;
+use pyo3::prelude::*;
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+/// Apply `f` to a single number, or to every number of a list or buffer.
+fn format_each<F>(py_input: &Bound<'_, PyAny>, f: F) -> PyResult<PyObject>
+where
+    F: Fn(f64) -> String,
+{
+    let py = py_input.py();
+    if let Ok(val) = py_input.extract::<f64>() {
+        return Ok(f(val).into_py(py));
+    }
+    with_floats(py_input, false, |floats| {
+        Ok(floats.iter().map(|&x| f(x)).collect::<Vec<_>>().into_py(py))
+    })
+}
+
+/// Apply `f` to a single string, or to every string of a list.
+fn parse_each<T, F>(py_input: &Bound<'_, PyAny>, f: F) -> PyResult<PyObject>
+where
+    T: IntoPy<PyObject>,
+    F: Fn(&str) -> Result<T, NumbersError>,
+{
+    let py = py_input.py();
+    if let Ok(s) = py_input.extract::<&str>() {
+        return Ok(f(s)?.into_py(py));
+    }
+    let strings: Vec<String> = py_input.extract()?;
+    let parsed = strings
+        .iter()
+        .map(|s| f(s))
+        .collect::<Result<Vec<T>, _>>()?;
+    Ok(parsed.into_py(py))
+}
+
+// ─── Python wrappers ─────────────────────────────────────────────────────────
+
+#[pyfunction]
+#[pyo3(signature = (py_input, decimals = 0, thousands = ",", decimal = "."))]
+pub fn format_number(
+    py_input: Bound<'_, PyAny>,
+    decimals: u32,
+    thousands: &str,
+    decimal: &str,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    // Integers keep their exact digits instead of going through f64.
+    if let Ok(val) = py_input.extract::<i64>() {
+        return Ok(format_integer_(val, decimals, thousands, decimal).into_py(py));
+    }
+    if let Ok(val) = py_input.extract::<f64>() {
+        return Ok(format_number_(val, decimals, thousands, decimal).into_py(py));
+    }
+    with_numbers(&py_input, false, |numbers| {
+        let formatted: Vec<String> = match numbers {
+            Numbers::Ints(ints) => ints
+                .iter()
+                .map(|&x| format_integer_(x, decimals, thousands, decimal))
+                .collect(),
+            Numbers::Floats(floats) => floats
+                .iter()
+                .map(|&x| format_number_(x, decimals, thousands, decimal))
+                .collect(),
+        };
+        Ok(formatted.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, binary = true, decimals = 1))]
+pub fn format_bytes(py_input: Bound<'_, PyAny>, binary: bool, decimals: u32) -> PyResult<PyObject> {
+    format_each(&py_input, |x| format_bytes_(x, binary, decimals))
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, decimals = 1))]
+pub fn format_compact(py_input: Bound<'_, PyAny>, decimals: u32) -> PyResult<PyObject> {
+    format_each(&py_input, |x| format_compact_(x, decimals))
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, decimals = 0))]
+pub fn format_percent(py_input: Bound<'_, PyAny>, decimals: u32) -> PyResult<PyObject> {
+    format_each(&py_input, |x| format_percent_(x, decimals))
+}
+
+#[pyfunction]
+pub fn format_duration(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    format_each(&py_input, format_duration_)
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, locale = "en"))]
+pub fn parse_number(py_input: Bound<'_, PyAny>, locale: &str) -> PyResult<PyObject> {
+    let locale: NumberLocale = locale.parse()?;
+    parse_each(&py_input, |s| parse_number_(s, locale))
+}
+
+#[pyfunction]
+pub fn parse_bytes(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    parse_each(&py_input, parse_bytes_)
+}
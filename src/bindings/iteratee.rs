@@ -0,0 +1,141 @@
+use crate::bindings::objects::walk_path;
+use crate::core::objects::{parse_path, Token};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyList, PyString, PyTuple};
+
+/// A lodash-style iteratee: a Python callable, or one of the shorthands resolved in
+/// Rust without a Python call per element:
+///
+/// - a property path string, such as `"user.name"`;
+/// - a dict, matching elements that contain it (`_.matches`);
+/// - a `(path, value)` pair, matching elements whose value at `path` contains `value`
+///   (`_.matchesProperty`). A `[path, value]` list is accepted too unless `value` is a
+///   string, since two strings in a list read like several sort keys.
+pub enum Iteratee<'py> {
+    Func(Bound<'py, PyAny>),
+    Path(Vec<Token>),
+    Matches(Bound<'py, PyDict>),
+    MatchesProperty(Vec<Token>, Bound<'py, PyAny>),
+}
+
+/// Whether a lookup error means the key is absent rather than a failing `__getitem__`.
+fn is_missing(py: Python<'_>, err: &PyErr) -> bool {
+    err.is_instance_of::<pyo3::exceptions::PyKeyError>(py)
+        || err.is_instance_of::<pyo3::exceptions::PyIndexError>(py)
+        || err.is_instance_of::<pyo3::exceptions::PyTypeError>(py)
+}
+
+/// lodash `isMatch`: every key of a source dict is present in `target` with a matching
+/// value. Nested dicts match partially, a source list matches when each of its elements
+/// matches some element of the target list, and anything else compares with `==`.
+fn is_match(target: &Bound<'_, PyAny>, source: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if let Ok(source) = source.downcast::<PyDict>() {
+        for (key, expected) in source.iter() {
+            let actual = match target.get_item(&key) {
+                Ok(actual) => actual,
+                Err(err) if is_missing(target.py(), &err) => return Ok(false),
+                Err(err) => return Err(err),
+            };
+            if !is_match(&actual, &expected)? {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+    if let Ok(source) = source.downcast::<PyList>() {
+        if !target.is_instance_of::<PyList>() && !target.is_instance_of::<PyTuple>() {
+            return Ok(false);
+        }
+        let actual: Vec<Bound<'_, PyAny>> = target.try_iter()?.collect::<PyResult<_>>()?;
+        for expected in source.iter() {
+            let mut found = false;
+            for item in &actual {
+                if is_match(item, &expected)? {
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+    target.eq(source)
+}
+
+/// A `(path, value)` pair, or a `[path, value]` list whose value is not a string.
+/// A list of two strings raises instead of silently matching the first path against
+/// the second string.
+fn path_value_pair<'py>(
+    py_iteratee: &Bound<'py, PyAny>,
+) -> PyResult<Option<(String, Bound<'py, PyAny>)>> {
+    let is_list = py_iteratee.is_instance_of::<PyList>();
+    if !is_list && !py_iteratee.is_instance_of::<PyTuple>() {
+        return Ok(None);
+    }
+    if py_iteratee.len()? != 2 {
+        return Ok(None);
+    }
+    let path = py_iteratee.get_item(0)?;
+    if !path.is_instance_of::<PyString>() {
+        return Ok(None);
+    }
+    let value = py_iteratee.get_item(1)?;
+    if is_list && value.is_instance_of::<PyString>() {
+        return Err(pyo3::exceptions::PyTypeError::new_err(
+            "a [path, value] list with a string value is ambiguous: pass a (path, value) \
+             tuple to match a property, or use order_by to sort by several keys",
+        ));
+    }
+    Ok(Some((path.extract()?, value)))
+}
+
+impl<'py> Iteratee<'py> {
+    pub fn new(py_iteratee: Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(path) = py_iteratee.extract::<&str>() {
+            return Ok(Iteratee::Path(parse_path(path)));
+        }
+        if let Ok(source) = py_iteratee.downcast::<PyDict>() {
+            return Ok(Iteratee::Matches(source.clone()));
+        }
+        if let Some((path, value)) = path_value_pair(&py_iteratee)? {
+            return Ok(Iteratee::MatchesProperty(parse_path(&path), value));
+        }
+        if py_iteratee.is_callable() {
+            return Ok(Iteratee::Func(py_iteratee));
+        }
+        Err(pyo3::exceptions::PyTypeError::new_err(
+            "Expected a callable, a path string, a dict or a (path, value) pair",
+        ))
+    }
+
+    /// Evaluate the iteratee for one element. Missing paths resolve to `None`.
+    pub fn call(&self, item: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = item.py();
+        match self {
+            Iteratee::Func(func) => func.call1((item,)),
+            Iteratee::Path(tokens) => Ok(walk_path(py, item, tokens)
+                .unwrap_or_else(|| py.None())
+                .into_bound(py)),
+            Iteratee::Matches(_) | Iteratee::MatchesProperty(..) => {
+                Ok(PyBool::new_bound(py, self.test(item)?)
+                    .to_owned()
+                    .into_any())
+            }
+        }
+    }
+
+    /// Evaluate the iteratee as a predicate, using the truthiness of its result.
+    pub fn test(&self, item: &Bound<'py, PyAny>) -> PyResult<bool> {
+        let py = item.py();
+        match self {
+            Iteratee::Func(_) | Iteratee::Path(_) => self.call(item)?.is_truthy(),
+            Iteratee::Matches(source) => is_match(item, source.as_any()),
+            Iteratee::MatchesProperty(tokens, value) => match walk_path(py, item, tokens) {
+                Some(actual) => is_match(actual.bind(py), value),
+                None => Ok(false),
+            },
+        }
+    }
+}
@@ -1,110 +1,964 @@
-use crate::core::numbers::{max_, mean_, min_, round_, sum_};
+use crate::bindings::arrays_helpers::{group_into, Collect};
+use crate::bindings::iteratee::Iteratee;
+use crate::bindings::numbers_helpers::{by_key, exact_key, with_floats, with_numbers, Numbers};
+use crate::core::numbers::{
+    apply_nan_policy_, argmax_, argmin_, clamp_, correlation_, covariance_, cummax_, cummin_,
+    cumprod_, cumsum_, diff_, digitize_, ewm_mean_, factorial_, fill_gaps_, gcd_, geometric_mean_,
+    harmonic_mean_, histogram_, in_range_, interp_, is_nan_, is_prime_, lcm_, lerp_,
+    linear_regression_, linspace_, max_, mean_, min_, min_max_, mod_pow_, n_choose_k_, normalize_,
+    pct_change_, primes_up_to_, range_, range_right_, resample_, rescale_, robust_scale_, rolling_,
+    round_, standardize_, sum_, times_, top_k_, weighted_mean_, CorrelationMethod, ExactNumber,
+    FillMethod, HistogramBins, NanPolicy, NumbersError, ResampleAgg, RollingStat,
+};
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyDict, PyFloat, PyInt, PyList, PyTuple};
+
+// ─── Helpers ────────────────────────────────────────────────────────────────
+
+/// Element whose key is the most `wanted` (Greater for max_by, Less for min_by).
+/// Ties keep the first element; with `Propagate` the first NaN key wins outright.
+/// Int and float keys are compared exactly in Rust; any other key (`Decimal`,
+/// `datetime`, strings, ...) falls back to Python rich comparison.
+fn extreme_by(
+    py_input: &Bound<'_, PyAny>,
+    py_iteratee: &Bound<'_, PyAny>,
+    wanted: std::cmp::Ordering,
+    policy: NanPolicy,
+    skip_none: bool,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let iteratee = Iteratee::new(py_iteratee.clone())?;
+    let list = py_input.downcast::<PyList>()?;
+    let mut best: Option<(Bound<'_, PyAny>, Bound<'_, PyAny>, Option<ExactNumber>)> = None;
+    for (i, item) in list.iter().enumerate() {
+        let key = iteratee.call(&item)?;
+        // `exact_key` applies `skip_none` and the NaN policy; a `None` key is either
+        // skipped or rejected there, so it never reaches the comparison.
+        let numeric =
+            if key.is_none() || key.is_instance_of::<PyFloat>() || key.is_instance_of::<PyInt>() {
+                match exact_key(&key, i, skip_none, policy)? {
+                    Some(val) if is_nan_(&val) => return Ok(item.unbind()),
+                    Some(val) => Some(val),
+                    None => continue,
+                }
+            } else {
+                None
+            };
+        let better = match &best {
+            None => true,
+            Some((_, best_key, best_numeric)) => match (numeric, best_numeric) {
+                (Some(val), Some(best_val)) => val.partial_cmp(best_val) == Some(wanted),
+                _ => key.compare(best_key)? == wanted,
+            },
+        };
+        if better {
+            best = Some((item, key, numeric));
+        }
+    }
+    match best {
+        Some((item, _, _)) => Ok(item.unbind()),
+        None => Ok(py.None()),
+    }
+}
+
+/// Numeric key of every element of `py_input`, evaluated with a callable or path iteratee.
+fn keys_by(
+    py_input: &Bound<'_, PyAny>,
+    py_iteratee: &Bound<'_, PyAny>,
+) -> PyResult<Vec<ExactNumber>> {
+    let iteratee = Iteratee::new(py_iteratee.clone())?;
+    let list = py_input.downcast::<PyList>()?;
+    let mut keys = Vec::with_capacity(list.len());
+    for (i, item) in list.iter().enumerate() {
+        let key = exact_key(&iteratee.call(&item)?, i, false, NanPolicy::Propagate)?;
+        keys.push(key.unwrap_or(ExactNumber::Float(f64::NAN)));
+    }
+    Ok(keys)
+}
+
+/// Call a function of Python's `math` module, for integer results beyond `i64`.
+fn python_math(py: Python<'_>, name: &str, args: Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    Ok(py
+        .import_bound("math")?
+        .getattr(name)?
+        .call1(args)?
+        .unbind())
+}
+
+/// Fold a list of ints with `f` on the int fast path, falling back to `math.<name>`
+/// for Python ints beyond `i64` or results that overflow.
+fn fold_ints(
+    py_input: &Bound<'_, PyAny>,
+    name: &str,
+    f: fn(&[i64]) -> Option<i64>,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let fast = with_numbers(py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(f(&ints)),
+        Numbers::Floats(_) => Ok(None),
+    })?;
+    if let Some(result) = fast {
+        return Ok(result.into_py(py));
+    }
+    let items = py_input.try_iter()?.collect::<PyResult<Vec<_>>>()?;
+    python_math(py, name, PyTuple::new_bound(py, items))
+}
+
+/// Cumulative `op` over Python ints, for results beyond `i64`.
+fn python_accumulate<'py, F>(py_input: &Bound<'py, PyAny>, op: F) -> PyResult<PyObject>
+where
+    F: Fn(&Bound<'py, PyAny>, &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>>,
+{
+    let mut result: Vec<Bound<'py, PyAny>> = Vec::new();
+    for item in py_input.try_iter()? {
+        let item = item?;
+        let next = match result.last() {
+            Some(prev) => op(prev, &item)?,
+            None => item,
+        };
+        result.push(next);
+    }
+    Ok(PyList::new_bound(py_input.py(), result).into())
+}
+
+/// Read the `bins` argument of `histogram`: a count, a list of edges or a method name.
+fn extract_bins(py_bins: &Bound<'_, PyAny>) -> PyResult<HistogramBins> {
+    if let Ok(method) = py_bins.extract::<&str>() {
+        return Ok(method.parse()?);
+    }
+    if let Ok(count) = py_bins.extract::<usize>() {
+        return Ok(HistogramBins::Count(count));
+    }
+    let edges = with_floats(py_bins, false, |edges| Ok(edges.to_vec())).map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(
+            "bins must be an int, a list of edges or a method name",
+        )
+    })?;
+    Ok(HistogramBins::Edges(edges))
+}
+
+/// Produce `range_` or `range_right_` output with lodash argument handling, keeping ints
+/// when every argument is an int. The step defaults to 1 or -1 depending on direction.
+fn range_impl(
+    start: Bound<'_, PyAny>,
+    end: Option<Bound<'_, PyAny>>,
+    step: Option<Bound<'_, PyAny>>,
+    right: bool,
+) -> PyResult<PyObject> {
+    let py = start.py();
+    // A lone argument is the end of a range starting at zero.
+    let (start, end) = match end {
+        Some(end) => (start, end),
+        None => (0i64.into_py(py).into_bound(py), start),
+    };
+    let ints = (
+        start.extract::<i64>(),
+        end.extract::<i64>(),
+        step.as_ref().map(|s| s.extract::<i64>()),
+    );
+    if let (Ok(start), Ok(end), None | Some(Ok(_))) = ints {
+        let step = match step {
+            Some(step) => step.extract::<i64>()?,
+            None if start <= end => 1,
+            None => -1,
+        };
+        let values = if right {
+            range_right_(start, end, step)?
+        } else {
+            range_(start, end, step)?
+        };
+        return Ok(values.into_py(py));
+    }
+    let (start, end): (f64, f64) = (start.extract()?, end.extract()?);
+    let step = match step {
+        Some(step) => step.extract::<f64>()?,
+        None if start <= end => 1.0,
+        None => -1.0,
+    };
+    let values = if right {
+        range_right_(start, end, step)?
+    } else {
+        range_(start, end, step)?
+    };
+    Ok(values.into_py(py))
+}
 
 // ─── Python wrappers ─────────────────────────────────────────────────────────
 
 #[pyfunction]
-pub fn sum(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, nan_policy = "propagate", skip_none = false))]
+pub fn sum(py_input: Bound<'_, PyAny>, nan_policy: &str, skip_none: bool) -> PyResult<PyObject> {
     let py = py_input.py();
-    if let Ok(ints) = py_input.extract::<Vec<i64>>() {
-        return Ok(sum_(&ints).into_py(py));
-    }
-    let floats = py_input
-        .extract::<Vec<f64>>()
-        .map_err(|_| pyo3::exceptions::PyTypeError::new_err("Expected a list of numbers"))?;
-    Ok(sum_(&floats).into_py(py))
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_numbers(&py_input, skip_none, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(sum_(&ints).into_py(py)),
+        Numbers::Floats(floats) => Ok(sum_(&apply_nan_policy_(&floats, policy)?).into_py(py)),
+    })
 }
 
 #[pyfunction]
-pub fn max(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, nan_policy = "propagate", skip_none = false))]
+pub fn max(py_input: Bound<'_, PyAny>, nan_policy: &str, skip_none: bool) -> PyResult<PyObject> {
     let py = py_input.py();
-    if let Ok(ints) = py_input.extract::<Vec<i64>>() {
-        return Ok(max_(&ints).into_py(py));
-    }
-    let floats = py_input
-        .extract::<Vec<f64>>()
-        .map_err(|_| pyo3::exceptions::PyTypeError::new_err("Expected a list of numbers"))?;
-    Ok(max_(&floats).into_py(py))
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_numbers(&py_input, skip_none, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(max_(&ints).into_py(py)),
+        Numbers::Floats(floats) => Ok(max_(&apply_nan_policy_(&floats, policy)?).into_py(py)),
+    })
 }
 
 #[pyfunction]
-pub fn min(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, nan_policy = "propagate", skip_none = false))]
+pub fn min(py_input: Bound<'_, PyAny>, nan_policy: &str, skip_none: bool) -> PyResult<PyObject> {
     let py = py_input.py();
-    if let Ok(ints) = py_input.extract::<Vec<i64>>() {
-        return Ok(min_(&ints).into_py(py));
-    }
-    let floats = py_input
-        .extract::<Vec<f64>>()
-        .map_err(|_| pyo3::exceptions::PyTypeError::new_err("Expected a list of numbers"))?;
-    Ok(min_(&floats).into_py(py))
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_numbers(&py_input, skip_none, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(min_(&ints).into_py(py)),
+        Numbers::Floats(floats) => Ok(min_(&apply_nan_policy_(&floats, policy)?).into_py(py)),
+    })
 }
 
 #[pyfunction]
-pub fn mean(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, nan_policy = "propagate", skip_none = false))]
+pub fn mean(py_input: Bound<'_, PyAny>, nan_policy: &str, skip_none: bool) -> PyResult<PyObject> {
     let py = py_input.py();
-    let floats = py_input
-        .extract::<Vec<f64>>()
-        .map_err(|_| pyo3::exceptions::PyTypeError::new_err("Expected a list of numbers"))?;
-    Ok(mean_(&floats).into_py(py))
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_floats(&py_input, skip_none, |floats| {
+        Ok(mean_(&apply_nan_policy_(floats, policy)?).into_py(py))
+    })
 }
 
 #[pyfunction]
-pub fn round(py_input: Bound<'_, PyAny>, decimals: u32) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, decimals, nan_policy = "propagate", skip_none = false))]
+pub fn round(
+    py_input: Bound<'_, PyAny>,
+    decimals: u32,
+    nan_policy: &str,
+    skip_none: bool,
+) -> PyResult<PyObject> {
     let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    // Elementwise: NaN and skipped None stay in place so the output lines up with the
+    // input, and only the "raise" policy has an effect.
+    let check = |val: f64| {
+        if val.is_nan() && policy == NanPolicy::Raise {
+            return Err(NumbersError::NanFound);
+        }
+        Ok(val)
+    };
     if let Ok(val) = py_input.extract::<f64>() {
-        return Ok(round_(val, decimals).into_py(py));
+        return Ok(round_(check(val)?, decimals).into_py(py));
+    }
+    let rounded = with_floats(&py_input, false, |floats| {
+        for &val in floats {
+            check(val)?;
+        }
+        Ok(round_(floats, decimals).into_py(py))
+    });
+    match rounded {
+        Err(err) if skip_none && err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) => {
+            let mut values = Vec::new();
+            for item in py_input.try_iter()? {
+                let item = item?;
+                values.push(if item.is_none() {
+                    py.None()
+                } else {
+                    round_(check(item.extract()?)?, decimals).into_py(py)
+                });
+            }
+            Ok(PyList::new_bound(py, values).into())
+        }
+        rounded => rounded,
     }
-    let floats = py_input
-        .extract::<Vec<f64>>()
-        .map_err(|_| pyo3::exceptions::PyTypeError::new_err("Expected a number or list of numbers"))?;
-    Ok(round_(&floats[..], decimals).into_py(py))
 }
 
 #[pyfunction]
-pub fn sum_by(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, py_func, nan_policy = "propagate", skip_none = false))]
+pub fn sum_by(
+    py_input: Bound<'_, PyAny>,
+    py_func: Bound<'_, PyAny>,
+    nan_policy: &str,
+    skip_none: bool,
+) -> PyResult<PyObject> {
     let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    let iteratee = Iteratee::new(py_func)?;
     let list = py_input.downcast::<PyList>()?;
     let mut total: f64 = 0.0;
-    for item in list.iter() {
-        let val: f64 = py_func.call1((&item,))?.extract()?;
-        total += val;
+    for (i, item) in list.iter().enumerate() {
+        if let Some(val) = by_key(&iteratee.call(&item)?, i, skip_none, policy)? {
+            total += val;
+        }
     }
     Ok(total.into_py(py))
 }
 
 #[pyfunction]
-pub fn max_by(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<PyObject> {
+#[pyo3(signature = (py_input, py_func, nan_policy = "propagate", skip_none = false))]
+pub fn max_by(
+    py_input: Bound<'_, PyAny>,
+    py_func: Bound<'_, PyAny>,
+    nan_policy: &str,
+    skip_none: bool,
+) -> PyResult<PyObject> {
+    let policy: NanPolicy = nan_policy.parse()?;
+    extreme_by(
+        &py_input,
+        &py_func,
+        std::cmp::Ordering::Greater,
+        policy,
+        skip_none,
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, py_func, nan_policy = "propagate", skip_none = false))]
+pub fn min_by(
+    py_input: Bound<'_, PyAny>,
+    py_func: Bound<'_, PyAny>,
+    nan_policy: &str,
+    skip_none: bool,
+) -> PyResult<PyObject> {
+    let policy: NanPolicy = nan_policy.parse()?;
+    extreme_by(
+        &py_input,
+        &py_func,
+        std::cmp::Ordering::Less,
+        policy,
+        skip_none,
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, py_func, nan_policy = "propagate", skip_none = false))]
+pub fn mean_by(
+    py_input: Bound<'_, PyAny>,
+    py_func: Bound<'_, PyAny>,
+    nan_policy: &str,
+    skip_none: bool,
+) -> PyResult<PyObject> {
     let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    let iteratee = Iteratee::new(py_func)?;
     let list = py_input.downcast::<PyList>()?;
-    let mut best: Option<(PyObject, f64)> = None;
-    for item in list.iter() {
-        let val: f64 = py_func.call1((&item,))?.extract()?;
-        match &best {
-            Some((_, best_val)) if val <= *best_val => {}
-            _ => best = Some((item.unbind(), val)),
+    let mut keys = Vec::with_capacity(list.len());
+    for (i, item) in list.iter().enumerate() {
+        if let Some(val) = by_key(&iteratee.call(&item)?, i, skip_none, policy)? {
+            keys.push(val);
         }
     }
-    match best {
-        Some((obj, _)) => Ok(obj),
-        None => Ok(py.None()),
-    }
+    Ok(mean_(&keys).into_py(py))
+}
+
+#[pyfunction]
+pub fn weighted_mean(
+    py_input: Bound<'_, PyAny>,
+    py_weights: Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_floats(&py_input, false, |values| {
+        with_floats(&py_weights, false, |weights| {
+            Ok(weighted_mean_(values, weights)?.into_py(py))
+        })
+    })
+}
+
+#[pyfunction]
+pub fn geometric_mean(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_floats(&py_input, false, |values| {
+        Ok(geometric_mean_(values)?.into_py(py))
+    })
 }
 
 #[pyfunction]
-pub fn min_by(py_input: Bound<'_, PyAny>, py_func: Bound<'_, PyAny>) -> PyResult<PyObject> {
+pub fn harmonic_mean(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_floats(&py_input, false, |values| {
+        Ok(harmonic_mean_(values)?.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, nan_policy = "propagate"))]
+pub fn cumsum(py_input: Bound<'_, PyAny>, nan_policy: &str) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => match cumsum_(&ints, policy) {
+            Err(NumbersError::Overflow) => python_accumulate(&py_input, |acc, x| acc.add(x)),
+            result => Ok(result?.into_py(py)),
+        },
+        Numbers::Floats(floats) => Ok(cumsum_(&floats, policy)?.into_py(py)),
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, nan_policy = "propagate"))]
+pub fn cumprod(py_input: Bound<'_, PyAny>, nan_policy: &str) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => match cumprod_(&ints, policy) {
+            Err(NumbersError::Overflow) => python_accumulate(&py_input, |acc, x| acc.mul(x)),
+            result => Ok(result?.into_py(py)),
+        },
+        Numbers::Floats(floats) => Ok(cumprod_(&floats, policy)?.into_py(py)),
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, nan_policy = "propagate"))]
+pub fn cummax(py_input: Bound<'_, PyAny>, nan_policy: &str) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(cummax_(&ints, policy)?.into_py(py)),
+        Numbers::Floats(floats) => Ok(cummax_(&floats, policy)?.into_py(py)),
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, nan_policy = "propagate"))]
+pub fn cummin(py_input: Bound<'_, PyAny>, nan_policy: &str) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(cummin_(&ints, policy)?.into_py(py)),
+        Numbers::Floats(floats) => Ok(cummin_(&floats, policy)?.into_py(py)),
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, lag = 1, nan_policy = "propagate"))]
+pub fn diff(py_input: Bound<'_, PyAny>, lag: usize, nan_policy: &str) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => match diff_(&ints, lag, policy) {
+            Err(NumbersError::Overflow) => {
+                let items = py_input.try_iter()?.collect::<PyResult<Vec<_>>>()?;
+                let diffs = items
+                    .iter()
+                    .zip(items.iter().skip(lag))
+                    .map(|(prev, x)| x.sub(prev))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(PyList::new_bound(py, diffs).into())
+            }
+            result => Ok(result?.into_py(py)),
+        },
+        Numbers::Floats(floats) => Ok(diff_(&floats, lag, policy)?.into_py(py)),
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, lag = 1, nan_policy = "propagate"))]
+pub fn pct_change(py_input: Bound<'_, PyAny>, lag: usize, nan_policy: &str) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_floats(&py_input, false, |floats| {
+        Ok(pct_change_(floats, lag, policy)?.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, window, stat = "mean", nan_policy = "propagate"))]
+pub fn rolling(
+    py_input: Bound<'_, PyAny>,
+    window: usize,
+    stat: &str,
+    nan_policy: &str,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let stat: RollingStat = stat.parse()?;
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_floats(&py_input, false, |floats| {
+        Ok(rolling_(floats, window, stat, policy)?.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, alpha, nan_policy = "propagate"))]
+pub fn ewm_mean(py_input: Bound<'_, PyAny>, alpha: f64, nan_policy: &str) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let policy: NanPolicy = nan_policy.parse()?;
+    with_floats(&py_input, false, |floats| {
+        Ok(ewm_mean_(floats, alpha, policy)?.into_py(py))
+    })
+}
+
+#[pyfunction]
+pub fn covariance(py_xs: Bound<'_, PyAny>, py_ys: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_xs.py();
+    with_floats(&py_xs, false, |xs| {
+        with_floats(&py_ys, false, |ys| Ok(covariance_(xs, ys)?.into_py(py)))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_xs, py_ys, method = "pearson"))]
+pub fn correlation(
+    py_xs: Bound<'_, PyAny>,
+    py_ys: Bound<'_, PyAny>,
+    method: &str,
+) -> PyResult<PyObject> {
+    let py = py_xs.py();
+    let method: CorrelationMethod = method.parse()?;
+    with_floats(&py_xs, false, |xs| {
+        with_floats(&py_ys, false, |ys| {
+            Ok(correlation_(xs, ys, method)?.into_py(py))
+        })
+    })
+}
+
+#[pyfunction]
+pub fn linear_regression(py_xs: Bound<'_, PyAny>, py_ys: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_xs.py();
+    let fit = with_floats(&py_xs, false, |xs| {
+        with_floats(&py_ys, false, |ys| Ok(linear_regression_(xs, ys)?))
+    })?;
+    let result = PyDict::new_bound(py);
+    result.set_item("slope", fit.slope)?;
+    result.set_item("intercept", fit.intercept)?;
+    result.set_item("r_squared", fit.r_squared)?;
+    Ok(result.into())
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, bins = None, range = None))]
+pub fn histogram(
+    py_input: Bound<'_, PyAny>,
+    bins: Option<Bound<'_, PyAny>>,
+    range: Option<(f64, f64)>,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let bins = match bins {
+        Some(py_bins) => extract_bins(&py_bins)?,
+        None => HistogramBins::Count(10),
+    };
+    let result = with_floats(&py_input, false, |values| {
+        Ok(histogram_(values, bins, range)?)
+    })?;
+    Ok((result.counts, result.edges).into_py(py))
+}
+
+#[pyfunction]
+pub fn digitize(py_input: Bound<'_, PyAny>, py_edges: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_floats(&py_input, false, |values| {
+        with_floats(&py_edges, false, |edges| {
+            Ok(digitize_(values, edges)?.into_py(py))
+        })
+    })
+}
+
+#[pyfunction]
+pub fn bin_by(
+    py_input: Bound<'_, PyAny>,
+    py_iteratee: Bound<'_, PyAny>,
+    py_edges: Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
     let py = py_input.py();
     let list = py_input.downcast::<PyList>()?;
-    let mut best: Option<(PyObject, f64)> = None;
+    let iteratee = Iteratee::new(py_iteratee)?;
+    let edges = with_floats(&py_edges, false, |edges| Ok(edges.to_vec()))?;
+
+    let mut keys = Vec::with_capacity(list.len());
     for item in list.iter() {
-        let val: f64 = py_func.call1((&item,))?.extract()?;
-        match &best {
-            Some((_, best_val)) if val >= *best_val => {}
-            _ => best = Some((item.unbind(), val)),
+        let key = iteratee.call(&item)?;
+        keys.push(if key.is_none() {
+            f64::NAN
+        } else {
+            key.extract::<f64>()?
+        });
+    }
+    let bins = digitize_(&keys, &edges)?;
+
+    // Missing and NaN keys are grouped under None rather than in the overflow bin.
+    let pairs = list.iter().zip(keys).zip(bins).map(|((item, key), bin)| {
+        let bin = if key.is_nan() {
+            py.None()
+        } else {
+            bin.into_py(py)
+        };
+        Ok((bin.into_bound(py), item))
+    });
+    Ok(group_into(py, pairs, Collect::All)?.into())
+}
+
+#[pyfunction]
+pub fn clamp(
+    py_input: Bound<'_, PyAny>,
+    lower: Bound<'_, PyAny>,
+    upper: Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let (lo, hi): (f64, f64) = (lower.extract()?, upper.extract()?);
+    if lo > hi {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "lower must not be greater than upper",
+        ));
+    }
+    let int_bounds = match (lower.extract::<i64>(), upper.extract::<i64>()) {
+        (Ok(lo), Ok(hi)) => Some((lo, hi)),
+        _ => None,
+    };
+    if let (Some((lo, hi)), Ok(val)) = (int_bounds, py_input.extract::<i64>()) {
+        return Ok(clamp_(val, lo, hi).into_py(py));
+    }
+    if let Ok(val) = py_input.extract::<f64>() {
+        return Ok(clamp_(val, lo, hi).into_py(py));
+    }
+    with_numbers(&py_input, false, |numbers| match (numbers, int_bounds) {
+        (Numbers::Ints(ints), Some((lo, hi))) => Ok(clamp_(&ints[..], lo, hi).into_py(py)),
+        (Numbers::Ints(ints), None) => {
+            let floats: Vec<f64> = ints.iter().map(|&x| x as f64).collect();
+            Ok(clamp_(&floats[..], lo, hi).into_py(py))
         }
+        (Numbers::Floats(floats), _) => Ok(clamp_(&floats[..], lo, hi).into_py(py)),
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, start, end = None))]
+pub fn in_range(py_input: Bound<'_, PyAny>, start: f64, end: Option<f64>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let (start, end) = match end {
+        Some(end) => (start, end),
+        None => (0.0, start),
+    };
+    if let Ok(val) = py_input.extract::<f64>() {
+        return Ok(in_range_(val, start, end).into_py(py));
     }
-    match best {
-        Some((obj, _)) => Ok(obj),
-        None => Ok(py.None()),
+    with_floats(&py_input, false, |floats| {
+        Ok(in_range_(floats, start, end).into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (start, end = None, step = None))]
+pub fn range(
+    start: Bound<'_, PyAny>,
+    end: Option<Bound<'_, PyAny>>,
+    step: Option<Bound<'_, PyAny>>,
+) -> PyResult<PyObject> {
+    range_impl(start, end, step, false)
+}
+
+#[pyfunction]
+#[pyo3(signature = (start, end = None, step = None))]
+pub fn range_right(
+    start: Bound<'_, PyAny>,
+    end: Option<Bound<'_, PyAny>>,
+    step: Option<Bound<'_, PyAny>>,
+) -> PyResult<PyObject> {
+    range_impl(start, end, step, true)
+}
+
+#[pyfunction]
+#[pyo3(signature = (start, stop, num = 50, endpoint = true))]
+pub fn linspace(
+    py: Python<'_>,
+    start: f64,
+    stop: f64,
+    num: usize,
+    endpoint: bool,
+) -> PyResult<PyObject> {
+    Ok(linspace_(start, stop, num, endpoint)?.into_py(py))
+}
+
+#[pyfunction]
+#[pyo3(signature = (n, py_func = None))]
+pub fn times(py: Python<'_>, n: usize, py_func: Option<Bound<'_, PyAny>>) -> PyResult<PyObject> {
+    let values = times_(n, |i| match &py_func {
+        Some(func) => func.call1((i,)).map(|v| v.unbind()),
+        None => Ok(i.into_py(py)),
+    })?;
+    Ok(PyList::new_bound(py, &values).into())
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, fill = None))]
+pub fn normalize(py_input: Bound<'_, PyAny>, fill: Option<f64>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_floats(&py_input, false, |floats| {
+        Ok(normalize_(floats, fill)?.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, fill = None))]
+pub fn standardize(py_input: Bound<'_, PyAny>, fill: Option<f64>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_floats(&py_input, false, |floats| {
+        Ok(standardize_(floats, fill)?.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, fill = None))]
+pub fn robust_scale(py_input: Bound<'_, PyAny>, fill: Option<f64>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_floats(&py_input, false, |floats| {
+        Ok(robust_scale_(floats, fill)?.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, new_min = 0.0, new_max = 1.0, fill = None))]
+pub fn rescale(
+    py_input: Bound<'_, PyAny>,
+    new_min: f64,
+    new_max: f64,
+    fill: Option<f64>,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_floats(&py_input, false, |floats| {
+        Ok(rescale_(floats, new_min, new_max, fill)?.into_py(py))
+    })
+}
+
+#[pyfunction]
+pub fn argmax(py_input: Bound<'_, PyAny>) -> PyResult<Option<usize>> {
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(argmax_(&ints)),
+        Numbers::Floats(floats) => Ok(argmax_(&floats)),
+    })
+}
+
+#[pyfunction]
+pub fn argmin(py_input: Bound<'_, PyAny>) -> PyResult<Option<usize>> {
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(argmin_(&ints)),
+        Numbers::Floats(floats) => Ok(argmin_(&floats)),
+    })
+}
+
+#[pyfunction]
+pub fn argmax_by(
+    py_input: Bound<'_, PyAny>,
+    py_iteratee: Bound<'_, PyAny>,
+) -> PyResult<Option<usize>> {
+    Ok(argmax_(&keys_by(&py_input, &py_iteratee)?))
+}
+
+#[pyfunction]
+pub fn argmin_by(
+    py_input: Bound<'_, PyAny>,
+    py_iteratee: Bound<'_, PyAny>,
+) -> PyResult<Option<usize>> {
+    Ok(argmin_(&keys_by(&py_input, &py_iteratee)?))
+}
+
+#[pyfunction]
+pub fn min_max(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(min_max_(&ints).into_py(py)),
+        Numbers::Floats(floats) => Ok(min_max_(&floats).into_py(py)),
+    })
+}
+
+#[pyfunction]
+pub fn min_max_by(py_input: Bound<'_, PyAny>, py_iteratee: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let keys = keys_by(&py_input, &py_iteratee)?;
+    let list = py_input.downcast::<PyList>()?;
+    match (argmin_(&keys), argmax_(&keys)) {
+        (Some(lo), Some(hi)) => Ok((list.get_item(lo)?, list.get_item(hi)?).into_py(py)),
+        _ => Ok(py.None()),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, k, key = None, largest = true))]
+pub fn top_k(
+    py_input: Bound<'_, PyAny>,
+    k: usize,
+    key: Option<Bound<'_, PyAny>>,
+    largest: bool,
+) -> PyResult<PyObject> {
+    let py = py_input.py();
+    if let Some(key) = key {
+        let indices = top_k_(&keys_by(&py_input, &key)?, k, largest);
+        let list = py_input.downcast::<PyList>()?;
+        let picked = indices
+            .into_iter()
+            .map(|i| list.get_item(i))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(PyList::new_bound(py, picked).into());
+    }
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => {
+            let picked: Vec<i64> = top_k_(&ints, k, largest)
+                .into_iter()
+                .map(|i| ints[i])
+                .collect();
+            Ok(picked.into_py(py))
+        }
+        Numbers::Floats(floats) => {
+            let picked: Vec<f64> = top_k_(&floats, k, largest)
+                .into_iter()
+                .map(|i| floats[i])
+                .collect();
+            Ok(picked.into_py(py))
+        }
+    })
+}
+
+#[pyfunction]
+pub fn gcd(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    fold_ints(&py_input, "gcd", gcd_)
+}
+
+#[pyfunction]
+pub fn lcm(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    fold_ints(&py_input, "lcm", lcm_)
+}
+
+#[pyfunction]
+pub fn factorial(py: Python<'_>, n: i64) -> PyResult<PyObject> {
+    if n < 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "factorial is not defined for negative values",
+        ));
+    }
+    match factorial_(n as u64) {
+        Some(result) => Ok(result.into_py(py)),
+        None => python_math(py, "factorial", PyTuple::new_bound(py, [n])),
+    }
+}
+
+#[pyfunction]
+pub fn n_choose_k(py: Python<'_>, n: i64, k: i64) -> PyResult<PyObject> {
+    if n < 0 || k < 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "n and k must be non-negative",
+        ));
+    }
+    match n_choose_k_(n as u64, k as u64) {
+        Some(result) => Ok(result.into_py(py)),
+        None => python_math(py, "comb", PyTuple::new_bound(py, [n, k])),
     }
 }
+
+#[pyfunction]
+pub fn mod_pow(
+    base: Bound<'_, PyAny>,
+    exp: Bound<'_, PyAny>,
+    modulus: Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    let py = base.py();
+    if let (Ok(b), Ok(e), Ok(m)) = (
+        base.extract::<i64>(),
+        exp.extract::<u64>(),
+        modulus.extract::<i64>(),
+    ) {
+        if m > 0 {
+            return Ok(mod_pow_(b, e, m)?.into_py(py));
+        }
+    }
+    // Big ints, negative exponents (modular inverses) and non-positive moduli follow
+    // Python's own three-argument `pow`.
+    Ok(py
+        .import_bound("builtins")?
+        .getattr("pow")?
+        .call1((base, exp, modulus))?
+        .unbind())
+}
+
+#[pyfunction]
+pub fn is_prime(py_input: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_input.py();
+    if py_input.is_instance_of::<PyInt>() {
+        return Ok(is_prime_(py_input.extract()?).into_py(py));
+    }
+    with_numbers(&py_input, false, |numbers| match numbers {
+        Numbers::Ints(ints) => Ok(ints
+            .iter()
+            .map(|&n| is_prime_(n))
+            .collect::<Vec<_>>()
+            .into_py(py)),
+        Numbers::Floats(_) => Err(pyo3::exceptions::PyTypeError::new_err(
+            "is_prime expects integers",
+        )),
+    })
+}
+
+#[pyfunction]
+pub fn primes_up_to(n: i64) -> PyResult<Vec<i64>> {
+    Ok(primes_up_to_(usize::try_from(n).unwrap_or(0))?)
+}
+
+#[pyfunction]
+pub fn interp(
+    py_x: Bound<'_, PyAny>,
+    py_xp: Bound<'_, PyAny>,
+    py_fp: Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    let py = py_x.py();
+    with_floats(&py_xp, false, |xp| {
+        with_floats(&py_fp, false, |fp| {
+            if let Ok(x) = py_x.extract::<f64>() {
+                return Ok(interp_(&[x], xp, fp)?[0].into_py(py));
+            }
+            with_floats(&py_x, false, |x| Ok(interp_(x, xp, fp)?.into_py(py)))
+        })
+    })
+}
+
+#[pyfunction]
+pub fn lerp(a: f64, b: f64, py_t: Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let py = py_t.py();
+    if let Ok(t) = py_t.extract::<f64>() {
+        return Ok(lerp_(a, b, t).into_py(py));
+    }
+    with_floats(&py_t, false, |ts| {
+        Ok(ts
+            .iter()
+            .map(|&t| lerp_(a, b, t))
+            .collect::<Vec<_>>()
+            .into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_input, method = "linear"))]
+pub fn fill_gaps(py_input: Bound<'_, PyAny>, method: &str) -> PyResult<PyObject> {
+    let py = py_input.py();
+    let method: FillMethod = method.parse()?;
+    let values: Vec<Option<f64>> = py_input.extract()?;
+    Ok(fill_gaps_(&values, method).into_py(py))
+}
+
+#[pyfunction]
+#[pyo3(signature = (py_points, step, agg = "mean"))]
+pub fn resample(
+    py_points: Bound<'_, PyAny>,
+    step: Bound<'_, PyAny>,
+    agg: &str,
+) -> PyResult<PyObject> {
+    let py = py_points.py();
+    let agg: ResampleAgg = agg.parse()?;
+    let value_of = |value: Option<f64>| match (agg, value) {
+        (ResampleAgg::Count, Some(count)) => (count as i64).into_py(py),
+        (_, value) => value.into_py(py),
+    };
+    // Integer timestamps with an integer step are bucketed exactly and keep integer
+    // bucket starts.
+    let int_points = py_points.extract::<Vec<(i64, f64)>>();
+    let rows: Vec<Bound<'_, PyTuple>> = match (step.extract::<i64>(), int_points) {
+        (Ok(step), Ok(points)) => resample_(&points, step, agg)?
+            .into_iter()
+            .map(|(start, value)| PyTuple::new_bound(py, [start.into_py(py), value_of(value)]))
+            .collect(),
+        _ => {
+            let points: Vec<(f64, f64)> = py_points.extract()?;
+            resample_(&points, step.extract::<f64>()?, agg)?
+                .into_iter()
+                .map(|(start, value)| PyTuple::new_bound(py, [start.into_py(py), value_of(value)]))
+                .collect()
+        }
+    };
+    Ok(PyList::new_bound(py, rows).into())
+}
@@ -0,0 +1,163 @@
+use crate::core::numbers::{ExactNumber, NanPolicy, NumbersError};
+use pyo3::buffer::{Element, PyBuffer};
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+use std::borrow::Cow;
+
+/// Numeric input read from Python, either as integers or as floats.
+/// C-contiguous int64/float64 buffers are borrowed in place; everything else is copied.
+pub enum Numbers<'a> {
+    Ints(Cow<'a, [i64]>),
+    Floats(Cow<'a, [f64]>),
+}
+
+/// Borrow a buffer as a plain slice when it is C-contiguous, copy it otherwise.
+///
+/// Writable buffers are borrowed too. As with numpy-based crates, this assumes the
+/// exporter's memory is only changed by code holding the GIL, which is held for as
+/// long as the slice lives; native code mutating it from another thread concurrently
+/// is a data race that the caller must avoid.
+fn buffer_slice<'a, T: Element>(py: Python<'a>, buffer: &'a PyBuffer<T>) -> PyResult<Cow<'a, [T]>> {
+    match buffer.as_slice(py) {
+        // SAFETY: `ReadOnlyCell<T>` is `repr(transparent)` over `T`, and the GIL is held
+        // while the slice lives, so Python code cannot mutate the buffer meanwhile.
+        Some(cells) => Ok(Cow::Borrowed(unsafe {
+            std::slice::from_raw_parts(cells.as_ptr() as *const T, cells.len())
+        })),
+        None => Ok(Cow::Owned(buffer.to_vec(py)?)),
+    }
+}
+
+/// Read raw bytes as native-endian float64 values.
+fn bytes_as_floats(raw: &[u8]) -> PyResult<Vec<f64>> {
+    if !raw.len().is_multiple_of(8) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "bytes length must be a multiple of 8 to be read as float64",
+        ));
+    }
+    Ok(raw
+        .chunks_exact(8)
+        .map(|c| f64::from_ne_bytes(c.try_into().unwrap()))
+        .collect())
+}
+
+/// Read a Python sequence of numbers, dropping `None` entries when `skip_none` is set.
+fn extract_sequence(py_input: &Bound<'_, PyAny>, skip_none: bool) -> PyResult<Numbers<'static>> {
+    if let Ok(ints) = py_input.extract::<Vec<i64>>() {
+        return Ok(Numbers::Ints(Cow::Owned(ints)));
+    }
+    if let Ok(floats) = py_input.extract::<Vec<f64>>() {
+        return Ok(Numbers::Floats(Cow::Owned(floats)));
+    }
+
+    // Slow path: walk the items to drop or report `None` entries.
+    let not_numbers = || pyo3::exceptions::PyTypeError::new_err("Expected a list of numbers");
+    let mut items = Vec::new();
+    for (i, item) in py_input.try_iter().map_err(|_| not_numbers())?.enumerate() {
+        let item = item?;
+        if item.is_none() {
+            if skip_none {
+                continue;
+            }
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Expected a list of numbers, found None at index {i} (pass skip_none=True to ignore it)"
+            )));
+        }
+        items.push(item);
+    }
+    if let Ok(ints) = items.iter().map(|x| x.extract::<i64>()).collect() {
+        return Ok(Numbers::Ints(Cow::Owned(ints)));
+    }
+    let floats = items
+        .iter()
+        .map(|x| x.extract::<f64>())
+        .collect::<PyResult<Vec<f64>>>()
+        .map_err(|_| not_numbers())?;
+    Ok(Numbers::Floats(Cow::Owned(floats)))
+}
+
+/// Run `f` over the numbers in `py_input`.
+///
+/// Accepts int64/float64 buffers (numpy arrays, `array.array`, `memoryview`),
+/// unsigned-byte buffers (`bytes`, `bytearray`, byte `memoryview`s) of doubles, and
+/// lists of ints or floats. Integer input keeps the int fast path.
+/// `None` entries in a list raise `TypeError` unless `skip_none` is set.
+pub fn with_numbers<R, F>(py_input: &Bound<'_, PyAny>, skip_none: bool, f: F) -> PyResult<R>
+where
+    F: FnOnce(Numbers<'_>) -> PyResult<R>,
+{
+    let py = py_input.py();
+    if let Ok(buffer) = PyBuffer::<i64>::get(py_input) {
+        return f(Numbers::Ints(buffer_slice(py, &buffer)?));
+    }
+    if let Ok(buffer) = PyBuffer::<f64>::get(py_input) {
+        return f(Numbers::Floats(buffer_slice(py, &buffer)?));
+    }
+    if let Ok(buffer) = PyBuffer::<u8>::get(py_input) {
+        let floats = bytes_as_floats(&buffer_slice(py, &buffer)?)?;
+        return f(Numbers::Floats(Cow::Owned(floats)));
+    }
+    f(extract_sequence(py_input, skip_none)?)
+}
+
+/// Run `f` over the numbers in `py_input` as floats, converting integer input.
+pub fn with_floats<R, F>(py_input: &Bound<'_, PyAny>, skip_none: bool, f: F) -> PyResult<R>
+where
+    F: FnOnce(&[f64]) -> PyResult<R>,
+{
+    with_numbers(py_input, skip_none, |numbers| match numbers {
+        Numbers::Ints(ints) => {
+            let floats: Vec<f64> = ints.iter().map(|&x| x as f64).collect();
+            f(&floats)
+        }
+        Numbers::Floats(floats) => f(&floats),
+    })
+}
+
+/// Extract the numeric key of one `_by` element, applying `skip_none` and `policy`.
+/// Returns `None` when the element should be left out of the aggregate.
+pub fn by_key(
+    key: &Bound<'_, PyAny>,
+    index: usize,
+    skip_none: bool,
+    policy: NanPolicy,
+) -> PyResult<Option<f64>> {
+    if key.is_none() {
+        if skip_none {
+            return Ok(None);
+        }
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Expected a number, got None at index {index} (pass skip_none=True to ignore it)"
+        )));
+    }
+    let value: f64 = key.extract()?;
+    if value.is_nan() {
+        match policy {
+            NanPolicy::Omit => return Ok(None),
+            NanPolicy::Raise => return Err(NumbersError::NanFound.into()),
+            NanPolicy::Propagate => {}
+        }
+    }
+    Ok(Some(value))
+}
+
+/// Like `by_key`, but ints that fit in 128 bits stay exact instead of going through `f64`.
+pub fn exact_key(
+    key: &Bound<'_, PyAny>,
+    index: usize,
+    skip_none: bool,
+    policy: NanPolicy,
+) -> PyResult<Option<ExactNumber>> {
+    if key.is_instance_of::<PyInt>() {
+        if let Ok(i) = key.extract::<i128>() {
+            return Ok(Some(ExactNumber::Int(i)));
+        }
+    }
+    Ok(by_key(key, index, skip_none, policy)?.map(ExactNumber::Float))
+}
+
+impl From<NumbersError> for PyErr {
+    fn from(err: NumbersError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
@@ -5,7 +5,7 @@ use pyo3::types::{PyDict, PyList};
 // ─── Helpers ────────────────────────────────────────────────────────────────
 
 /// Walk a Python object along parsed path tokens, returning the value or None.
-fn walk_path<'py>(
+pub(crate) fn walk_path<'py>(
     py: Python<'py>,
     obj: &Bound<'py, PyAny>,
     tokens: &[Token],
@@ -28,6 +28,76 @@ fn walk_path<'py>(
     Some(current)
 }
 
+/// The value stored under one path token, if any.
+fn get_slot<'py>(container: &Bound<'py, PyAny>, token: &Token) -> Option<Bound<'py, PyAny>> {
+    match token {
+        Token::Key(key) => container.get_item(key.as_str()).ok(),
+        Token::Index(i) => container.downcast::<PyList>().ok()?.get_item(*i).ok(),
+        Token::Wildcard => None,
+    }
+}
+
+/// How far past the end of a list a path index may reach; the gap is padded with None.
+const MAX_INDEX_PADDING: usize = 1 << 20;
+
+/// Store `value` under one path token, padding lists with None up to the index.
+fn set_slot(container: &Bound<'_, PyAny>, token: &Token, value: &Bound<'_, PyAny>) -> PyResult<()> {
+    match token {
+        Token::Key(key) => container.set_item(key.as_str(), value),
+        Token::Index(i) => {
+            let list = container.downcast::<PyList>()?;
+            let len = list.len();
+            if *i >= len {
+                if *i - len >= MAX_INDEX_PADDING {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "path index {i} is too far past the end of a list of length {len}"
+                    )));
+                }
+                let py = container.py();
+                let padding = PyList::new_bound(py, (len..*i + 1).map(|_| py.None()));
+                list.set_slice(len, len, &padding)?;
+            }
+            list.set_item(*i, value)
+        }
+        Token::Wildcard => Err(pyo3::exceptions::PyValueError::new_err(
+            "cannot set a path containing a wildcard",
+        )),
+    }
+}
+
+/// Set `value` at a path inside `root`, creating dicts for keys and lists for indices
+/// along the way. Values in the way that are not the container the path needs are
+/// replaced.
+pub(crate) fn set_path(
+    root: &Bound<'_, PyAny>,
+    tokens: &[Token],
+    value: &Bound<'_, PyAny>,
+) -> PyResult<()> {
+    let py = root.py();
+    let mut current = root.clone();
+    for (token, next) in tokens.iter().zip(tokens.iter().skip(1)) {
+        let existing = get_slot(&current, token).filter(|existing| match next {
+            Token::Index(_) => existing.is_instance_of::<PyList>(),
+            _ => existing.is_instance_of::<PyDict>(),
+        });
+        current = match existing {
+            Some(existing) => existing,
+            None => {
+                let child = match next {
+                    Token::Index(_) => PyList::empty_bound(py).into_any(),
+                    _ => PyDict::new_bound(py).into_any(),
+                };
+                set_slot(&current, token, &child)?;
+                child
+            }
+        };
+    }
+    match tokens.last() {
+        Some(last) => set_slot(&current, last, value),
+        None => Ok(()),
+    }
+}
+
 /// Recursively resolve a wildcard path, collecting all matching PyObjects.
 fn resolve_path_py<'py>(
     py: Python<'py>,
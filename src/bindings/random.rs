@@ -0,0 +1,209 @@
+use crate::bindings::numbers_helpers::with_floats;
+use crate::core::random::{sample_, sample_size_, shuffle_, weighted_choice_, Reservoir, Rng};
+use pyo3::prelude::*;
+use pyo3::types::{PyFloat, PyList};
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+fn rng_for(seed: Option<u64>) -> Rng {
+    match seed {
+        Some(seed) => Rng::new(seed),
+        None => Rng::from_entropy(),
+    }
+}
+
+/// lodash `random`: a single bound means `[0, bound]`, and a float bound switches to floats.
+fn random_with(
+    py: Python<'_>,
+    rng: &mut Rng,
+    lower: Option<Bound<'_, PyAny>>,
+    upper: Option<Bound<'_, PyAny>>,
+    floating: bool,
+) -> PyResult<PyObject> {
+    let (lower, upper) = match (lower, upper) {
+        (Some(lower), None) => (None, Some(lower)),
+        bounds => bounds,
+    };
+    let is_float =
+        |b: &Option<Bound<'_, PyAny>>| b.as_ref().is_some_and(|b| b.is_instance_of::<PyFloat>());
+    if floating || is_float(&lower) || is_float(&upper) {
+        let lo = lower
+            .map(|b| b.extract::<f64>())
+            .transpose()?
+            .unwrap_or(0.0);
+        let hi = upper
+            .map(|b| b.extract::<f64>())
+            .transpose()?
+            .unwrap_or(1.0);
+        return Ok(rng.float_between(lo, hi).into_py(py));
+    }
+    let lo = lower.map(|b| b.extract::<i64>()).transpose()?.unwrap_or(0);
+    let hi = upper.map(|b| b.extract::<i64>()).transpose()?.unwrap_or(1);
+    Ok(rng.int_between(lo, hi).into_py(py))
+}
+
+fn shuffle_with(py: Python<'_>, rng: &mut Rng, list: Vec<PyObject>) -> PyObject {
+    let mut items = list;
+    shuffle_(rng, &mut items);
+    PyList::new_bound(py, items).into()
+}
+
+fn sample_with(py: Python<'_>, rng: &mut Rng, list: Vec<PyObject>) -> PyObject {
+    match sample_(rng, &list) {
+        Some(i) => list[i].clone_ref(py),
+        None => py.None(),
+    }
+}
+
+fn sample_size_with(py: Python<'_>, rng: &mut Rng, list: Vec<PyObject>, n: usize) -> PyObject {
+    let picked: Vec<PyObject> = sample_size_(rng, list.len(), n)
+        .into_iter()
+        .map(|i| list[i].clone_ref(py))
+        .collect();
+    PyList::new_bound(py, picked).into()
+}
+
+fn weighted_choice_with(
+    py: Python<'_>,
+    rng: &mut Rng,
+    list: Vec<PyObject>,
+    weights: &Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    with_floats(weights, false, |weights| {
+        if weights.len() != list.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "list and weights must have the same length",
+            ));
+        }
+        Ok(match weighted_choice_(rng, weights)? {
+            Some(i) => list[i].clone_ref(py),
+            None => py.None(),
+        })
+    })
+}
+
+/// Consume any iterable lazily, keeping only `k` items in memory.
+fn reservoir_sample_with(
+    py: Python<'_>,
+    rng: &mut Rng,
+    iterable: &Bound<'_, PyAny>,
+    k: usize,
+) -> PyResult<PyObject> {
+    let mut reservoir = Reservoir::new(k);
+    for item in iterable.try_iter()? {
+        reservoir.push(rng, item?.unbind());
+    }
+    Ok(PyList::new_bound(py, reservoir.into_vec()).into())
+}
+
+// ─── Python wrappers ─────────────────────────────────────────────────────────
+
+#[pyfunction]
+#[pyo3(signature = (lower = None, upper = None, floating = false, seed = None))]
+pub fn random(
+    py: Python<'_>,
+    lower: Option<Bound<'_, PyAny>>,
+    upper: Option<Bound<'_, PyAny>>,
+    floating: bool,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    random_with(py, &mut rng_for(seed), lower, upper, floating)
+}
+
+#[pyfunction]
+#[pyo3(signature = (list, seed = None))]
+pub fn shuffle(py: Python<'_>, list: Vec<PyObject>, seed: Option<u64>) -> PyObject {
+    shuffle_with(py, &mut rng_for(seed), list)
+}
+
+#[pyfunction]
+#[pyo3(signature = (list, seed = None))]
+pub fn sample(py: Python<'_>, list: Vec<PyObject>, seed: Option<u64>) -> PyObject {
+    sample_with(py, &mut rng_for(seed), list)
+}
+
+#[pyfunction]
+#[pyo3(signature = (list, n = 1, seed = None))]
+pub fn sample_size(py: Python<'_>, list: Vec<PyObject>, n: usize, seed: Option<u64>) -> PyObject {
+    sample_size_with(py, &mut rng_for(seed), list, n)
+}
+
+#[pyfunction]
+#[pyo3(signature = (list, weights, seed = None))]
+pub fn weighted_choice(
+    py: Python<'_>,
+    list: Vec<PyObject>,
+    weights: Bound<'_, PyAny>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    weighted_choice_with(py, &mut rng_for(seed), list, &weights)
+}
+
+#[pyfunction]
+#[pyo3(signature = (iterable, k, seed = None))]
+pub fn reservoir_sample(
+    py: Python<'_>,
+    iterable: Bound<'_, PyAny>,
+    k: usize,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    reservoir_sample_with(py, &mut rng_for(seed), &iterable, k)
+}
+
+/// Independent random stream; every method advances the same generator.
+#[pyclass(name = "Random", module = "rustdash._rustdash")]
+#[derive(Clone)]
+pub struct PyRandom {
+    rng: Rng,
+}
+
+#[pymethods]
+impl PyRandom {
+    #[new]
+    #[pyo3(signature = (seed = None))]
+    fn new(seed: Option<u64>) -> Self {
+        PyRandom { rng: rng_for(seed) }
+    }
+
+    #[pyo3(signature = (lower = None, upper = None, floating = false))]
+    fn random(
+        &mut self,
+        py: Python<'_>,
+        lower: Option<Bound<'_, PyAny>>,
+        upper: Option<Bound<'_, PyAny>>,
+        floating: bool,
+    ) -> PyResult<PyObject> {
+        random_with(py, &mut self.rng, lower, upper, floating)
+    }
+
+    fn shuffle(&mut self, py: Python<'_>, list: Vec<PyObject>) -> PyObject {
+        shuffle_with(py, &mut self.rng, list)
+    }
+
+    fn sample(&mut self, py: Python<'_>, list: Vec<PyObject>) -> PyObject {
+        sample_with(py, &mut self.rng, list)
+    }
+
+    #[pyo3(signature = (list, n = 1))]
+    fn sample_size(&mut self, py: Python<'_>, list: Vec<PyObject>, n: usize) -> PyObject {
+        sample_size_with(py, &mut self.rng, list, n)
+    }
+
+    fn weighted_choice(
+        &mut self,
+        py: Python<'_>,
+        list: Vec<PyObject>,
+        weights: Bound<'_, PyAny>,
+    ) -> PyResult<PyObject> {
+        weighted_choice_with(py, &mut self.rng, list, &weights)
+    }
+
+    fn reservoir_sample(
+        &mut self,
+        py: Python<'_>,
+        iterable: Bound<'_, PyAny>,
+        k: usize,
+    ) -> PyResult<PyObject> {
+        reservoir_sample_with(py, &mut self.rng, &iterable, k)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::bindings::numbers_helpers::with_floats;
+use crate::core::running_stats::{check_compression, RunningStats};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyIterator};
+
+/// Python wrapper for the streaming statistics accumulator.
+#[pyclass(name = "RunningStats", module = "rustdash._rustdash")]
+#[derive(Clone)]
+pub struct PyRunningStats {
+    inner: RunningStats,
+}
+
+#[pymethods]
+impl PyRunningStats {
+    #[new]
+    #[pyo3(signature = (compression = 100.0))]
+    fn new(compression: f64) -> PyResult<Self> {
+        check_compression(compression)?;
+        Ok(PyRunningStats {
+            inner: RunningStats::new(compression),
+        })
+    }
+
+    fn push(&mut self, value: f64) {
+        self.inner.push(value);
+    }
+
+    /// Push every value of a list, buffer or iterator. Iterators are consumed lazily.
+    fn extend(&mut self, py_input: Bound<'_, PyAny>) -> PyResult<()> {
+        if let Ok(iterator) = py_input.downcast::<PyIterator>() {
+            for item in iterator.clone() {
+                self.inner.push(item?.extract()?);
+            }
+            return Ok(());
+        }
+        with_floats(&py_input, false, |values| {
+            self.inner.extend(values);
+            Ok(())
+        })
+    }
+
+    fn merge(slf: &Bound<'_, Self>, other: &Bound<'_, Self>) {
+        // `other` cannot be borrowed while `slf` is borrowed mutably if they are the
+        // same object, so merging into itself goes through a copy.
+        if slf.is(other) {
+            let copy = slf.borrow().inner.clone();
+            slf.borrow_mut().inner.merge(&copy);
+        } else {
+            slf.borrow_mut().inner.merge(&other.borrow().inner);
+        }
+    }
+
+    fn quantile(&mut self, q: f64) -> PyResult<Option<f64>> {
+        Ok(self.inner.quantile(q)?)
+    }
+
+    #[getter]
+    fn count(&self) -> u64 {
+        self.inner.count()
+    }
+
+    #[getter]
+    fn mean(&self) -> Option<f64> {
+        self.inner.mean()
+    }
+
+    #[getter]
+    fn variance(&self) -> Option<f64> {
+        self.inner.variance()
+    }
+
+    #[getter]
+    fn std(&self) -> Option<f64> {
+        self.inner.std()
+    }
+
+    #[getter]
+    fn min(&self) -> Option<f64> {
+        self.inner.min()
+    }
+
+    #[getter]
+    fn max(&self) -> Option<f64> {
+        self.inner.max()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.count() as usize
+    }
+
+    fn __repr__(&self) -> String {
+        match self.inner.mean() {
+            Some(mean) => format!("RunningStats(count={}, mean={})", self.inner.count(), mean),
+            None => "RunningStats(count=0)".to_string(),
+        }
+    }
+
+    fn __getstate__<'py>(&mut self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.inner.to_bytes())
+    }
+
+    fn __setstate__(&mut self, state: &[u8]) -> PyResult<()> {
+        self.inner = RunningStats::from_bytes(state)?;
+        Ok(())
+    }
+}
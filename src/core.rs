@@ -1,6 +1,10 @@
 pub mod strings;
 pub use strings::*;
+pub mod arrays;
+pub mod format;
 pub mod numbers;
 pub mod strings_helpers;
 pub use numbers::*;
 pub mod objects;
+pub mod random;
+pub mod running_stats;
@@ -0,0 +1,499 @@
+use crate::core::numbers::{max_, mean_, median_, min_, sum_, variance_};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Range;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArraysError {
+    /// An argument is outside of its valid domain.
+    InvalidArgument(String),
+}
+
+impl std::fmt::Display for ArraysError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArraysError::InvalidArgument(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ArraysError {}
+
+// ─── Deduplication ───────────────────────────────────────────────────────────
+
+/// Which occurrence of a duplicated key `unique_` keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    First,
+    Last,
+}
+
+impl FromStr for Keep {
+    type Err = ArraysError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "first" => Ok(Keep::First),
+            "last" => Ok(Keep::Last),
+            _ => Err(ArraysError::InvalidArgument(format!(
+                "keep must be 'first' or 'last', got '{s}'"
+            ))),
+        }
+    }
+}
+
+/// Indices of one occurrence per distinct key, in input order.
+pub fn unique_<K: Hash + Eq>(keys: &[K], keep: Keep) -> Vec<usize> {
+    let mut seen = HashSet::new();
+    match keep {
+        Keep::First => (0..keys.len()).filter(|&i| seen.insert(&keys[i])).collect(),
+        Keep::Last => {
+            let mut kept: Vec<usize> = (0..keys.len())
+                .rev()
+                .filter(|&i| seen.insert(&keys[i]))
+                .collect();
+            kept.reverse();
+            kept
+        }
+    }
+}
+
+// ─── Set operations ──────────────────────────────────────────────────────────
+// Each operation takes the keys of several lists and returns the `(list, index)`
+// positions of the elements to keep, so callers can map back to the original values.
+
+/// Positions of the unique elements of the first list whose key occurs in every list.
+pub fn intersection_<K: Hash + Eq>(lists: &[Vec<K>]) -> Vec<(usize, usize)> {
+    let Some((first, others)) = lists.split_first() else {
+        return Vec::new();
+    };
+    let others: Vec<HashSet<&K>> = others.iter().map(|l| l.iter().collect()).collect();
+    let mut seen = HashSet::new();
+    first
+        .iter()
+        .enumerate()
+        .filter(|(_, k)| others.iter().all(|set| set.contains(k)) && seen.insert(*k))
+        .map(|(i, _)| (0, i))
+        .collect()
+}
+
+/// Positions of the elements of the first list whose key occurs in none of the others.
+/// Like lodash, duplicates within the first list are kept.
+pub fn difference_<K: Hash + Eq>(lists: &[Vec<K>]) -> Vec<(usize, usize)> {
+    let Some((first, others)) = lists.split_first() else {
+        return Vec::new();
+    };
+    let excluded: HashSet<&K> = others.iter().flatten().collect();
+    first
+        .iter()
+        .enumerate()
+        .filter(|(_, k)| !excluded.contains(k))
+        .map(|(i, _)| (0, i))
+        .collect()
+}
+
+/// Positions of the first occurrence of every distinct key, across all lists in order.
+pub fn union_<K: Hash + Eq>(lists: &[Vec<K>]) -> Vec<(usize, usize)> {
+    let mut seen = HashSet::new();
+    let mut kept = Vec::new();
+    for (l, list) in lists.iter().enumerate() {
+        for (i, k) in list.iter().enumerate() {
+            if seen.insert(k) {
+                kept.push((l, i));
+            }
+        }
+    }
+    kept
+}
+
+/// Positions of the first occurrence of every key found in exactly one list
+/// (the symmetric difference).
+pub fn xor_<K: Hash + Eq>(lists: &[Vec<K>]) -> Vec<(usize, usize)> {
+    let mut lists_containing: HashMap<&K, usize> = HashMap::new();
+    for list in lists {
+        let distinct: HashSet<&K> = list.iter().collect();
+        for k in distinct {
+            *lists_containing.entry(k).or_default() += 1;
+        }
+    }
+    let mut seen = HashSet::new();
+    let mut kept = Vec::new();
+    for (l, list) in lists.iter().enumerate() {
+        for (i, k) in list.iter().enumerate() {
+            if lists_containing[k] == 1 && seen.insert(k) {
+                kept.push((l, i));
+            }
+        }
+    }
+    kept
+}
+
+// ─── Sorting ─────────────────────────────────────────────────────────────────
+
+/// Direction of one `order_by_` key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl FromStr for SortOrder {
+    type Err = ArraysError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(ArraysError::InvalidArgument(format!(
+                "order must be 'asc' or 'desc', got '{s}'"
+            ))),
+        }
+    }
+}
+
+/// Where missing keys go, independently of the sort direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullsPosition {
+    First,
+    Last,
+}
+
+impl FromStr for NullsPosition {
+    type Err = ArraysError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "first" => Ok(NullsPosition::First),
+            "last" => Ok(NullsPosition::Last),
+            _ => Err(ArraysError::InvalidArgument(format!(
+                "nulls must be 'first' or 'last', got '{s}'"
+            ))),
+        }
+    }
+}
+
+/// Stable merge sort with a fallible comparator; the first error aborts the sort.
+///
+/// `slice::sort_by` cannot stop early, and a comparator that starts answering `Equal`
+/// after a failure is no longer a total order.
+pub fn try_sort_by<T: Copy, E>(
+    items: &mut [T],
+    compare: &mut impl FnMut(&T, &T) -> Result<std::cmp::Ordering, E>,
+) -> Result<(), E> {
+    if items.len() <= 1 {
+        return Ok(());
+    }
+    let mid = items.len() / 2;
+    try_sort_by(&mut items[..mid], compare)?;
+    try_sort_by(&mut items[mid..], compare)?;
+    // Already in order: nothing to merge.
+    if compare(&items[mid - 1], &items[mid])?.is_le() {
+        return Ok(());
+    }
+    let left = items[..mid].to_vec();
+    let (mut i, mut j, mut k) = (0, mid, 0);
+    while i < left.len() && j < items.len() {
+        // Taking from the left on ties keeps the sort stable.
+        if compare(&items[j], &left[i])?.is_lt() {
+            items[k] = items[j];
+            j += 1;
+        } else {
+            items[k] = left[i];
+            i += 1;
+        }
+        k += 1;
+    }
+    while i < left.len() {
+        items[k] = left[i];
+        i += 1;
+        k += 1;
+    }
+    Ok(())
+}
+
+/// Stable order of `len` rows described by `columns`, where `columns[c][i]` is the
+/// `c`-th key of row `i` (`None` for a missing key). Later keys break ties of earlier
+/// ones; `orders` shorter than `columns` leaves the remaining keys ascending. Without
+/// any column the rows keep their order.
+pub fn order_by_<K, E>(
+    len: usize,
+    columns: &[Vec<Option<K>>],
+    orders: &[SortOrder],
+    nulls: NullsPosition,
+    mut compare: impl FnMut(&K, &K) -> Result<std::cmp::Ordering, E>,
+) -> Result<Vec<usize>, E> {
+    use std::cmp::Ordering;
+    let null_first = match nulls {
+        NullsPosition::First => Ordering::Less,
+        NullsPosition::Last => Ordering::Greater,
+    };
+    let mut indices: Vec<usize> = (0..len).collect();
+    try_sort_by(&mut indices, &mut |&a, &b| {
+        for (c, column) in columns.iter().enumerate() {
+            let ordering = match (&column[a], &column[b]) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => null_first,
+                (Some(_), None) => null_first.reverse(),
+                (Some(x), Some(y)) => match orders.get(c) {
+                    Some(SortOrder::Desc) => compare(x, y)?.reverse(),
+                    _ => compare(x, y)?,
+                },
+            };
+            if ordering.is_ne() {
+                return Ok(ordering);
+            }
+        }
+        Ok(Ordering::Equal)
+    })?;
+    Ok(indices)
+}
+
+// ─── Aggregation ─────────────────────────────────────────────────────────────
+
+/// One aggregate computed per group by `aggregate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggFunc {
+    Count,
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Median,
+    Std,
+    Var,
+    First,
+    Last,
+}
+
+impl FromStr for AggFunc {
+    type Err = ArraysError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "count" => Ok(AggFunc::Count),
+            "sum" => Ok(AggFunc::Sum),
+            "mean" => Ok(AggFunc::Mean),
+            "min" => Ok(AggFunc::Min),
+            "max" => Ok(AggFunc::Max),
+            "median" => Ok(AggFunc::Median),
+            "std" => Ok(AggFunc::Std),
+            "var" => Ok(AggFunc::Var),
+            "first" => Ok(AggFunc::First),
+            "last" => Ok(AggFunc::Last),
+            _ => Err(ArraysError::InvalidArgument(format!(
+                "agg must be 'count', 'sum', 'mean', 'min', 'max', 'median', 'std', 'var', \
+                 'first' or 'last', got '{s}'"
+            ))),
+        }
+    }
+}
+
+impl AggFunc {
+    /// Whether the aggregate reduces numbers (as opposed to counting or picking values).
+    pub fn is_numeric(self) -> bool {
+        !matches!(self, AggFunc::Count | AggFunc::First | AggFunc::Last)
+    }
+}
+
+/// A numeric aggregate; integer inputs keep exact integer sums, minima and maxima.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AggNumber {
+    Int(i128),
+    Float(f64),
+}
+
+/// One group's values for a numeric aggregate, kept as integers until a float arrives.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupValues {
+    Ints(Vec<i128>),
+    Floats(Vec<f64>),
+}
+
+impl Default for GroupValues {
+    fn default() -> Self {
+        GroupValues::Ints(Vec::new())
+    }
+}
+
+impl GroupValues {
+    pub fn push_int(&mut self, value: i128) {
+        match self {
+            GroupValues::Ints(ints) => ints.push(value),
+            GroupValues::Floats(floats) => floats.push(value as f64),
+        }
+    }
+
+    pub fn push_float(&mut self, value: f64) {
+        if let GroupValues::Ints(ints) = self {
+            *self = GroupValues::Floats(ints.iter().map(|&i| i as f64).collect());
+        }
+        if let GroupValues::Floats(floats) = self {
+            floats.push(value);
+        }
+    }
+
+    fn floats(&self) -> Vec<f64> {
+        match self {
+            GroupValues::Ints(ints) => ints.iter().map(|&i| i as f64).collect(),
+            GroupValues::Floats(floats) => floats.clone(),
+        }
+    }
+
+    /// Apply a numeric aggregate. Empty groups give `None`, except for `Sum`, which
+    /// gives 0; `Std` and `Var` use `ddof = 1` and need two values.
+    pub fn aggregate(&self, agg: AggFunc) -> Option<AggNumber> {
+        if let GroupValues::Ints(ints) = self {
+            match agg {
+                // Integer sums are exact; only a sum beyond 128 bits falls through to floats.
+                AggFunc::Sum => {
+                    if let Some(total) = ints.iter().try_fold(0i128, |acc, &i| acc.checked_add(i)) {
+                        return Some(AggNumber::Int(total));
+                    }
+                }
+                AggFunc::Min => return ints.iter().min().copied().map(AggNumber::Int),
+                AggFunc::Max => return ints.iter().max().copied().map(AggNumber::Int),
+                _ => {}
+            }
+        }
+        let floats = self.floats();
+        let value = match agg {
+            AggFunc::Sum => Some(sum_(&floats)),
+            AggFunc::Mean => mean_(&floats),
+            AggFunc::Min => min_(&floats),
+            AggFunc::Max => max_(&floats),
+            AggFunc::Median => median_(&floats),
+            AggFunc::Var => variance_(&floats),
+            AggFunc::Std => variance_(&floats).map(f64::sqrt),
+            AggFunc::Count | AggFunc::First | AggFunc::Last => None,
+        };
+        value.map(AggNumber::Float)
+    }
+}
+
+// ─── Joins ───────────────────────────────────────────────────────────────────
+
+/// Which unmatched rows `join_` keeps, as in SQL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinHow {
+    Inner,
+    Left,
+    Right,
+    Outer,
+}
+
+impl FromStr for JoinHow {
+    type Err = ArraysError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "inner" => Ok(JoinHow::Inner),
+            "left" => Ok(JoinHow::Left),
+            "right" => Ok(JoinHow::Right),
+            "outer" => Ok(JoinHow::Outer),
+            _ => Err(ArraysError::InvalidArgument(format!(
+                "how must be 'inner', 'left', 'right' or 'outer', got '{s}'"
+            ))),
+        }
+    }
+}
+
+/// Hash join on key ids, returning `(left row, right row)` index pairs; `None` marks the
+/// missing side of an unmatched row.
+///
+/// A key matching several rows on the other side yields one pair per match. `None` keys
+/// never match anything, not even each other. Pairs follow the left rows in order (the
+/// right rows for `Right`), each with its matches in order; an outer join then appends
+/// the unmatched right rows.
+pub fn join_(
+    left: &[Option<usize>],
+    right: &[Option<usize>],
+    how: JoinHow,
+) -> Vec<(Option<usize>, Option<usize>)> {
+    let swapped = how == JoinHow::Right;
+    let (outer, inner) = if swapped {
+        (right, left)
+    } else {
+        (left, right)
+    };
+    let mut index: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (j, id) in inner.iter().enumerate() {
+        if let Some(id) = id {
+            index.entry(*id).or_default().push(j);
+        }
+    }
+    let mut inner_matched = vec![false; inner.len()];
+    let mut pairs = Vec::new();
+    for (i, id) in outer.iter().enumerate() {
+        let matches = id
+            .and_then(|id| index.get(&id))
+            .map_or(&[][..], Vec::as_slice);
+        if matches.is_empty() && how != JoinHow::Inner {
+            pairs.push((Some(i), None));
+        }
+        for &j in matches {
+            inner_matched[j] = true;
+            pairs.push((Some(i), Some(j)));
+        }
+    }
+    if how == JoinHow::Outer {
+        let unmatched = (0..inner.len()).filter(|&j| !inner_matched[j]);
+        pairs.extend(unmatched.map(|j| (None, Some(j))));
+    }
+    if swapped {
+        for pair in &mut pairs {
+            *pair = (pair.1, pair.0);
+        }
+    }
+    pairs
+}
+
+// ─── Windows and chunks ──────────────────────────────────────────────────────
+
+fn check_positive(name: &str, value: usize) -> Result<(), ArraysError> {
+    if value == 0 {
+        return Err(ArraysError::InvalidArgument(format!(
+            "{name} must be a positive integer"
+        )));
+    }
+    Ok(())
+}
+
+/// Ranges of the windows of `size` elements starting every `step` elements. Trailing
+/// windows shorter than `size` are kept only when `partial` is set.
+pub fn windows_(
+    len: usize,
+    size: usize,
+    step: usize,
+    partial: bool,
+) -> Result<Vec<Range<usize>>, ArraysError> {
+    check_positive("size", size)?;
+    check_positive("step", step)?;
+    Ok((0..len)
+        .step_by(step)
+        .map(|start| start..len.min(start + size))
+        .take_while(|window| partial || window.len() == size)
+        .collect())
+}
+
+/// Ranges of consecutive non-overlapping chunks of `size` elements. The last chunk is
+/// shorter when `size` does not divide `len`, which is an error when `strict` is set.
+pub fn chunks_(len: usize, size: usize, strict: bool) -> Result<Vec<Range<usize>>, ArraysError> {
+    check_positive("size", size)?;
+    if strict && !len.is_multiple_of(size) {
+        return Err(ArraysError::InvalidArgument(format!(
+            "a list of length {len} does not split evenly into chunks of {size}"
+        )));
+    }
+    windows_(len, size, size, true)
+}
+
+/// The split point for a Python-style index: negative values count from the end and
+/// out-of-range values clamp to the bounds, as for slicing.
+pub fn split_index_(len: usize, idx: isize) -> usize {
+    if idx < 0 {
+        len.saturating_sub(idx.unsigned_abs())
+    } else {
+        len.min(idx as usize)
+    }
+}
@@ -0,0 +1,251 @@
+use crate::core::numbers::{round_, NumbersError};
+use std::str::FromStr;
+
+// ─── Formatting ──────────────────────────────────────────────────────────────
+
+/// Insert `thousands` between every group of three digits of an unsigned digit string.
+fn group_digits(digits: &str, thousands: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * thousands.len());
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push_str(thousands);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// Drop trailing fractional zeros (and a bare decimal point) from a formatted number.
+fn trim_zeros(formatted: String) -> String {
+    if !formatted.contains('.') {
+        return formatted;
+    }
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Round half away from zero with `Round`, then print exactly `decimals` digits.
+fn fixed(x: f64, decimals: u32) -> String {
+    let rounded = round_(x, decimals);
+    // Very large `decimals` overflow the scaling in `round_`; the value is already exact then.
+    let rounded = if rounded.is_finite() { rounded } else { x };
+    // Avoid printing "-0" for small negatives that round to zero.
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+    format!("{:.*}", decimals as usize, rounded)
+}
+
+/// Format with a fixed number of decimals and locale-specific separators,
+/// e.g. `1234567.891` → `"1,234,567.89"`.
+pub fn format_number_(x: f64, decimals: u32, thousands: &str, decimal: &str) -> String {
+    if !x.is_finite() {
+        return x.to_string();
+    }
+    let formatted = fixed(x, decimals);
+    let (sign, unsigned) = match formatted.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", formatted.as_str()),
+    };
+    match unsigned.split_once('.') {
+        Some((int, frac)) => format!("{sign}{}{decimal}{frac}", group_digits(int, thousands)),
+        None => format!("{sign}{}", group_digits(unsigned, thousands)),
+    }
+}
+
+/// Integer counterpart of `format_number_`, exact for the whole `i64` range.
+pub fn format_integer_(x: i64, decimals: u32, thousands: &str, decimal: &str) -> String {
+    let sign = if x < 0 { "-" } else { "" };
+    let grouped = group_digits(&x.unsigned_abs().to_string(), thousands);
+    if decimals == 0 {
+        return format!("{sign}{grouped}");
+    }
+    format!("{sign}{grouped}{decimal}{}", "0".repeat(decimals as usize))
+}
+
+/// Scale `x` down by `base` until it fits under one unit, returning the scaled value
+/// and the unit index. A value that rounds up to `base` moves to the next unit.
+fn scale(x: f64, base: f64, units: usize, decimals: u32) -> (f64, usize) {
+    let mut value = x;
+    let mut unit = 0;
+    while unit + 1 < units && round_(value.abs(), decimals) >= base {
+        value /= base;
+        unit += 1;
+    }
+    (value, unit)
+}
+
+const BINARY_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const DECIMAL_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Human-readable byte size, e.g. `1572864` → `"1.5 MiB"` (or `"1.6 MB"` when not `binary`).
+pub fn format_bytes_(n: f64, binary: bool, decimals: u32) -> String {
+    if !n.is_finite() {
+        return n.to_string();
+    }
+    let (base, units) = if binary {
+        (1024.0, BINARY_UNITS)
+    } else {
+        (1000.0, DECIMAL_UNITS)
+    };
+    let (value, unit) = scale(n, base, units.len(), decimals);
+    if unit == 0 {
+        return format!("{} B", fixed(value, 0));
+    }
+    format!("{} {}", trim_zeros(fixed(value, decimals)), units[unit])
+}
+
+const COMPACT_SUFFIXES: [&str; 5] = ["", "k", "M", "B", "T"];
+
+/// Short form with a magnitude suffix, e.g. `1234` → `"1.2k"`, `2500000` → `"2.5M"`.
+pub fn format_compact_(n: f64, decimals: u32) -> String {
+    if !n.is_finite() {
+        return n.to_string();
+    }
+    let (value, unit) = scale(n, 1000.0, COMPACT_SUFFIXES.len(), decimals);
+    format!(
+        "{}{}",
+        trim_zeros(fixed(value, decimals)),
+        COMPACT_SUFFIXES[unit]
+    )
+}
+
+/// Fraction as a percentage, e.g. `0.125` → `"12.5%"` with one decimal.
+pub fn format_percent_(x: f64, decimals: u32) -> String {
+    if !x.is_finite() {
+        return x.to_string();
+    }
+    format!("{}%", fixed(x * 100.0, decimals))
+}
+
+/// Duration in the largest fitting units, e.g. `3725` → `"1h 2m 5s"`, `1.5` → `"1.5s"`,
+/// `0.25` → `"250ms"`.
+pub fn format_duration_(seconds: f64) -> String {
+    if !seconds.is_finite() {
+        return seconds.to_string();
+    }
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let total = seconds.abs();
+    if total == 0.0 {
+        return "0s".to_string();
+    }
+    // Compare after rounding, so 59.97 reads "1m" rather than "60s".
+    if round_(total * 1000.0, 0) < 1000.0 {
+        return format!("{sign}{}ms", fixed(total * 1000.0, 0));
+    }
+    if round_(total, 1) < 60.0 {
+        return format!("{sign}{}s", trim_zeros(fixed(total, 1)));
+    }
+    let mut rest = round_(total, 0) as u64;
+    let mut parts = Vec::new();
+    for (unit, size) in [("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)] {
+        if rest >= size {
+            parts.push(format!("{}{unit}", rest / size));
+            rest %= size;
+        }
+    }
+    format!("{sign}{}", parts.join(" "))
+}
+
+// ─── Parsing ─────────────────────────────────────────────────────────────────
+
+/// Separator conventions for `parse_number_`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberLocale {
+    /// `1,234.56` (English, Chinese, Japanese, ...).
+    Point,
+    /// `1.234,56` (German, Spanish, Italian, Dutch, Portuguese, ...).
+    Comma,
+    /// `1 234,56` (French, Russian, Polish, Nordic, ...).
+    Space,
+    /// `1'234.56` (Swiss).
+    Apostrophe,
+}
+
+impl FromStr for NumberLocale {
+    type Err = NumbersError;
+
+    /// Accepts a language code or full locale tag such as `"de"`, `"de_DE"` or `"fr-CA"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s.to_ascii_lowercase().replace('-', "_");
+        if matches!(tag.as_str(), "de_ch" | "fr_ch" | "it_ch") {
+            return Ok(NumberLocale::Apostrophe);
+        }
+        let language = tag.split('_').next().unwrap_or_default();
+        match language {
+            "en" | "zh" | "ja" | "ko" | "th" | "he" | "hi" => Ok(NumberLocale::Point),
+            "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" => {
+                Ok(NumberLocale::Comma)
+            }
+            "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "uk" | "hu" => {
+                Ok(NumberLocale::Space)
+            }
+            _ => Err(NumbersError::InvalidArgument(format!(
+                "unsupported locale: {s}"
+            ))),
+        }
+    }
+}
+
+/// Parse a localized number such as `"1.234,56"` (`Comma`) or `"1 234,56"` (`Space`).
+pub fn parse_number_(s: &str, locale: NumberLocale) -> Result<f64, NumbersError> {
+    let (is_thousands, decimal): (fn(char) -> bool, char) = match locale {
+        NumberLocale::Point => (|c| c == ',', '.'),
+        NumberLocale::Comma => (|c| c == '.', ','),
+        // Spaces, including the no-break spaces locales actually print.
+        NumberLocale::Space => (char::is_whitespace, ','),
+        NumberLocale::Apostrophe => (|c| c == '\'' || c == '\u{2019}', '.'),
+    };
+    let normalized: String = s
+        .trim()
+        .chars()
+        .filter(|&c| !is_thousands(c))
+        .map(|c| if c == decimal { '.' } else { c })
+        .collect();
+    let invalid = || NumbersError::InvalidArgument(format!("could not parse number: {s:?}"));
+    // `f64::from_str` would also accept words like "inf" and "NaN".
+    if normalized.is_empty()
+        || !normalized
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+    {
+        return Err(invalid());
+    }
+    normalized.parse().map_err(|_| invalid())
+}
+
+/// Parse a byte size such as `"10MB"`, `"1.5 GiB"` or `"512"`.
+///
+/// Units are case-insensitive; `k`/`kB` are powers of 1000 and `KiB` powers of 1024.
+pub fn parse_bytes_(s: &str) -> Result<u64, NumbersError> {
+    let invalid = || NumbersError::InvalidArgument(format!("could not parse byte size: {s:?}"));
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let value: f64 = number.parse().map_err(|_| invalid())?;
+    let unit = unit.trim().to_ascii_lowercase();
+    let unit = unit.strip_suffix('b').unwrap_or(&unit);
+    let (prefix, base) = match unit.strip_suffix('i') {
+        Some(prefix) if !prefix.is_empty() => (prefix, 1024f64),
+        _ => (unit, 1000f64),
+    };
+    let exponent = match prefix {
+        "" => 0,
+        "k" => 1,
+        "m" => 2,
+        "g" => 3,
+        "t" => 4,
+        "p" => 5,
+        "e" => 6,
+        _ => return Err(invalid()),
+    };
+    let bytes = (value * base.powi(exponent)).round();
+    if bytes > u64::MAX as f64 {
+        return Err(NumbersError::InvalidArgument(format!(
+            "byte size out of range: {s:?}"
+        )));
+    }
+    Ok(bytes as u64)
+}
@@ -1,4 +1,5 @@
 // sum can take any type that implements the Sum trait (list, array, vector, etc...)
+// Empty input sums to zero; min_, max_ and mean_ return None instead.
 
 pub fn sum_<T>(iter: &[T]) -> T
 where
@@ -7,30 +8,174 @@ where
     iter.iter().copied().sum()
 }
 
+/// Largest value, or `None` for an empty slice.
+/// A NaN anywhere in the input is returned as the result, regardless of its position.
 pub fn max_<T>(slice: &[T]) -> Option<T>
 where
     T: PartialOrd + Copy,
 {
-    slice
-        .iter()
-        .copied()
-        .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+    extreme(slice, std::cmp::Ordering::Greater)
 }
 
+/// Smallest value, or `None` for an empty slice.
+/// A NaN anywhere in the input is returned as the result, regardless of its position.
 pub fn min_<T>(slice: &[T]) -> Option<T>
 where
     T: PartialOrd + Copy,
 {
-    slice
-        .iter()
-        .copied()
-        .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+    extreme(slice, std::cmp::Ordering::Less)
+}
+
+fn extreme<T>(slice: &[T], wanted: std::cmp::Ordering) -> Option<T>
+where
+    T: PartialOrd + Copy,
+{
+    extreme_index(slice, wanted).map(|i| slice[i])
+}
+
+/// Index of the first most `wanted` value, or of the first NaN.
+fn extreme_index<T>(slice: &[T], wanted: std::cmp::Ordering) -> Option<usize>
+where
+    T: PartialOrd + Copy,
+{
+    let first = slice.first()?;
+    if is_nan_(first) {
+        return Some(0);
+    }
+    let mut best = 0;
+    for (i, x) in slice.iter().enumerate().skip(1) {
+        match x.partial_cmp(&slice[best]) {
+            Some(ord) if ord == wanted => best = i,
+            Some(_) => {}
+            None => return Some(i),
+        }
+    }
+    Some(best)
+}
+
+/// Index of the largest value (the first one on ties), or `None` for an empty slice.
+/// Like `max_`, a NaN wins: its index is returned.
+pub fn argmax_<T>(slice: &[T]) -> Option<usize>
+where
+    T: PartialOrd + Copy,
+{
+    extreme_index(slice, std::cmp::Ordering::Greater)
 }
-pub fn mean_<T>(slice: &[T]) -> f64
+
+/// Index of the smallest value (the first one on ties), or `None` for an empty slice.
+/// Like `min_`, a NaN wins: its index is returned.
+pub fn argmin_<T>(slice: &[T]) -> Option<usize>
+where
+    T: PartialOrd + Copy,
+{
+    extreme_index(slice, std::cmp::Ordering::Less)
+}
+
+/// `(min_, max_)` in a single traversal, or `None` for an empty slice.
+/// A NaN anywhere in the input is returned for both.
+pub fn min_max_<T>(slice: &[T]) -> Option<(T, T)>
+where
+    T: PartialOrd + Copy,
+{
+    let &first = slice.first()?;
+    let (mut lo, mut hi) = (first, first);
+    for &x in slice {
+        if is_nan_(&x) {
+            return Some((x, x));
+        }
+        if x < lo {
+            lo = x;
+        } else if x > hi {
+            hi = x;
+        }
+    }
+    Some((lo, hi))
+}
+
+/// Arithmetic mean, or `None` for an empty slice.
+pub fn mean_<T>(slice: &[T]) -> Option<f64>
 where
     T: std::convert::Into<f64> + std::iter::Sum + Copy,
 {
-    sum_(slice).into() / slice.len() as f64
+    if slice.is_empty() {
+        return None;
+    }
+    Some(sum_(slice).into() / slice.len() as f64)
+}
+
+/// Mean of `values` weighted by `weights`, or `None` for empty input.
+/// Weights must match the values in length, be non-negative and not all zero.
+pub fn weighted_mean_<T, W>(values: &[T], weights: &[W]) -> Result<Option<f64>, NumbersError>
+where
+    T: std::convert::Into<f64> + Copy,
+    W: std::convert::Into<f64> + Copy,
+{
+    if values.len() != weights.len() {
+        return Err(NumbersError::InvalidArgument(format!(
+            "values and weights must have the same length ({} != {})",
+            values.len(),
+            weights.len()
+        )));
+    }
+    if values.is_empty() {
+        return Ok(None);
+    }
+    let mut total = 0.0;
+    let mut total_weight = 0.0;
+    for (&x, &w) in values.iter().zip(weights) {
+        let w: f64 = w.into();
+        if w < 0.0 {
+            return Err(NumbersError::InvalidArgument(
+                "weights must be non-negative".into(),
+            ));
+        }
+        total += x.into() * w;
+        total_weight += w;
+    }
+    if total_weight == 0.0 {
+        return Err(NumbersError::InvalidArgument(
+            "weights must not all be zero".into(),
+        ));
+    }
+    Ok(Some(total / total_weight))
+}
+
+fn check_positive<T>(values: &[T], name: &str) -> Result<(), NumbersError>
+where
+    T: std::convert::Into<f64> + Copy,
+{
+    if values.iter().any(|&x| x.into() <= 0.0) {
+        return Err(NumbersError::InvalidArgument(format!(
+            "{name} requires strictly positive values"
+        )));
+    }
+    Ok(())
+}
+
+/// Geometric mean, or `None` for empty input. Every value must be positive.
+pub fn geometric_mean_<T>(values: &[T]) -> Result<Option<f64>, NumbersError>
+where
+    T: std::convert::Into<f64> + Copy,
+{
+    check_positive(values, "geometric_mean")?;
+    if values.is_empty() {
+        return Ok(None);
+    }
+    let log_sum: f64 = values.iter().map(|&x| x.into().ln()).sum();
+    Ok(Some((log_sum / values.len() as f64).exp()))
+}
+
+/// Harmonic mean, or `None` for empty input. Every value must be positive.
+pub fn harmonic_mean_<T>(values: &[T]) -> Result<Option<f64>, NumbersError>
+where
+    T: std::convert::Into<f64> + Copy,
+{
+    check_positive(values, "harmonic_mean")?;
+    if values.is_empty() {
+        return Ok(None);
+    }
+    let inverse_sum: f64 = values.iter().map(|&x| 1.0 / x.into()).sum();
+    Ok(Some(values.len() as f64 / inverse_sum))
 }
 
 pub trait Round {
@@ -59,3 +204,1624 @@ where
 {
     value.round_(decimals)
 }
+
+/// Clamp into `[lower, upper]`. Callers are expected to pass `lower <= upper`.
+pub trait Clamp {
+    type Bound;
+    type Output;
+
+    fn clamp_(self, lower: Self::Bound, upper: Self::Bound) -> Self::Output;
+}
+
+impl Clamp for f64 {
+    type Bound = f64;
+    type Output = f64;
+    fn clamp_(self, lower: f64, upper: f64) -> Self::Output {
+        // Comparisons rather than `f64::max`/`min`, so NaN passes through unchanged.
+        if self < lower {
+            lower
+        } else if self > upper {
+            upper
+        } else {
+            self
+        }
+    }
+}
+
+impl Clamp for i64 {
+    type Bound = i64;
+    type Output = i64;
+    fn clamp_(self, lower: i64, upper: i64) -> Self::Output {
+        self.max(lower).min(upper)
+    }
+}
+
+impl Clamp for &[f64] {
+    type Bound = f64;
+    type Output = Vec<f64>;
+    fn clamp_(self, lower: f64, upper: f64) -> Self::Output {
+        self.iter().map(|x| x.clamp_(lower, upper)).collect()
+    }
+}
+
+impl Clamp for &[i64] {
+    type Bound = i64;
+    type Output = Vec<i64>;
+    fn clamp_(self, lower: i64, upper: i64) -> Self::Output {
+        self.iter().map(|x| x.clamp_(lower, upper)).collect()
+    }
+}
+
+pub fn clamp_<T>(value: T, lower: T::Bound, upper: T::Bound) -> T::Output
+where
+    T: Clamp,
+{
+    value.clamp_(lower, upper)
+}
+
+/// Whether a value lies in `[start, end)`. The bounds are swapped when `start > end`.
+pub trait InRange {
+    type Output;
+
+    fn in_range_(self, start: f64, end: f64) -> Self::Output;
+}
+
+impl InRange for f64 {
+    type Output = bool;
+    fn in_range_(self, start: f64, end: f64) -> Self::Output {
+        let (start, end) = if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        };
+        self >= start && self < end
+    }
+}
+
+impl InRange for &[f64] {
+    type Output = Vec<bool>;
+    fn in_range_(self, start: f64, end: f64) -> Self::Output {
+        self.iter().map(|x| x.in_range_(start, end)).collect()
+    }
+}
+
+pub fn in_range_<T>(value: T, start: f64, end: f64) -> T::Output
+where
+    T: InRange,
+{
+    value.in_range_(start, end)
+}
+
+/// Arithmetic progressions for `range_`. Each value is computed as `start + i * step`
+/// so float ranges do not accumulate rounding error.
+pub trait Range: Sized {
+    fn range_(start: Self, end: Self, step: Self) -> Result<Vec<Self>, NumbersError>;
+}
+
+impl Range for i64 {
+    fn range_(start: i64, end: i64, step: i64) -> Result<Vec<i64>, NumbersError> {
+        if step == 0 {
+            return Err(NumbersError::InvalidArgument(
+                "step must not be zero".into(),
+            ));
+        }
+        let span = end as i128 - start as i128;
+        let count = if span == 0 || (span > 0) != (step > 0) {
+            0
+        } else {
+            (span.abs() + step.unsigned_abs() as i128 - 1) / step.unsigned_abs() as i128
+        };
+        let count = usize::try_from(count).unwrap_or(usize::MAX);
+        let mut values = with_capacity(count)?;
+        // Every value lies between start and end, so the arithmetic cannot overflow.
+        values.extend((0..count as i128).map(|i| (start as i128 + i * step as i128) as i64));
+        Ok(values)
+    }
+}
+
+impl Range for f64 {
+    fn range_(start: f64, end: f64, step: f64) -> Result<Vec<f64>, NumbersError> {
+        if step == 0.0 || !step.is_finite() || !start.is_finite() || !end.is_finite() {
+            return Err(NumbersError::InvalidArgument(
+                "start, end and step must be finite and step must not be zero".into(),
+            ));
+        }
+        let mut count = ((end - start) / step).ceil().max(0.0) as usize;
+        // The division can land just above an integer; never emit `end` itself.
+        while count > 0 && (start + (count - 1) as f64 * step - end) * step.signum() >= 0.0 {
+            count -= 1;
+        }
+        let mut values = with_capacity(count)?;
+        values.extend((0..count).map(|i| start + i as f64 * step));
+        Ok(values)
+    }
+}
+
+/// Values from `start` up to, but not including, `end`, spaced by `step`.
+pub fn range_<T>(start: T, end: T, step: T) -> Result<Vec<T>, NumbersError>
+where
+    T: Range,
+{
+    T::range_(start, end, step)
+}
+
+/// `range_` in descending order.
+pub fn range_right_<T>(start: T, end: T, step: T) -> Result<Vec<T>, NumbersError>
+where
+    T: Range,
+{
+    let mut values = T::range_(start, end, step)?;
+    values.reverse();
+    Ok(values)
+}
+
+/// `num` evenly spaced values from `start` to `stop`, which is included when `endpoint` is set.
+pub fn linspace_(
+    start: f64,
+    stop: f64,
+    num: usize,
+    endpoint: bool,
+) -> Result<Vec<f64>, NumbersError> {
+    let mut values = with_capacity(num)?;
+    let divisions = if endpoint { num.saturating_sub(1) } else { num };
+    if divisions == 0 {
+        values.resize(num, start);
+        return Ok(values);
+    }
+    let step = (stop - start) / divisions as f64;
+    values.extend((0..num).map(|i| start + i as f64 * step));
+    if endpoint {
+        values[num - 1] = stop;
+    }
+    Ok(values)
+}
+
+/// Call `f` with each index in `0..n`, stopping at the first error.
+pub fn times_<T, E, F>(n: usize, f: F) -> Result<Vec<T>, E>
+where
+    F: FnMut(usize) -> Result<T, E>,
+{
+    (0..n).map(f).collect()
+}
+
+// ─── NaN handling ────────────────────────────────────────────────────────────
+
+/// What to do when a NaN shows up in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// NaN flows through the computation and poisons the results it touches.
+    Propagate,
+    /// NaN values are skipped by the computation.
+    Omit,
+    /// Any NaN makes the computation fail with `NumbersError::NanFound`.
+    Raise,
+}
+
+impl std::str::FromStr for NanPolicy {
+    type Err = NumbersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "propagate" => Ok(NanPolicy::Propagate),
+            "omit" => Ok(NanPolicy::Omit),
+            "raise" => Ok(NanPolicy::Raise),
+            _ => Err(NumbersError::InvalidArgument(format!(
+                "nan_policy must be 'propagate', 'omit' or 'raise', got '{s}'"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumbersError {
+    /// A NaN was found while `NanPolicy::Raise` was in effect.
+    NanFound,
+    /// An argument is outside of its valid domain.
+    InvalidArgument(String),
+    /// An integer result does not fit in `i64`.
+    Overflow,
+}
+
+impl std::fmt::Display for NumbersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumbersError::NanFound => write!(f, "input contains NaN"),
+            NumbersError::Overflow => write!(f, "integer result does not fit in 64 bits"),
+            NumbersError::InvalidArgument(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for NumbersError {}
+
+/// An empty vector with room for `len` values, failing instead of aborting the process
+/// when a user-provided length cannot be allocated.
+fn with_capacity<T>(len: usize) -> Result<Vec<T>, NumbersError> {
+    let mut values = Vec::new();
+    values
+        .try_reserve_exact(len)
+        .map_err(|_| NumbersError::InvalidArgument(format!("cannot allocate {len} values")))?;
+    Ok(values)
+}
+
+/// NaN is the only value that is not comparable with itself, so this works for
+/// any `PartialOrd` type and is always false for integers.
+pub fn is_nan_<T: PartialOrd>(value: &T) -> bool {
+    value.partial_cmp(value).is_none()
+}
+
+fn check_nan<T: PartialOrd>(slice: &[T], policy: NanPolicy) -> Result<(), NumbersError> {
+    if policy == NanPolicy::Raise && slice.iter().any(is_nan_) {
+        return Err(NumbersError::NanFound);
+    }
+    Ok(())
+}
+
+/// Prepare a slice for an aggregate according to `policy`: `Omit` drops NaN values,
+/// `Raise` fails on the first NaN and `Propagate` leaves the input untouched.
+/// Only allocates when NaN values actually have to be dropped.
+pub fn apply_nan_policy_<T>(
+    slice: &[T],
+    policy: NanPolicy,
+) -> Result<std::borrow::Cow<'_, [T]>, NumbersError>
+where
+    T: PartialOrd + Copy,
+{
+    check_nan(slice, policy)?;
+    if policy == NanPolicy::Omit && slice.iter().any(is_nan_) {
+        let kept = slice.iter().copied().filter(|x| !is_nan_(x)).collect();
+        return Ok(std::borrow::Cow::Owned(kept));
+    }
+    Ok(std::borrow::Cow::Borrowed(slice))
+}
+
+// ─── Cumulative operations ───────────────────────────────────────────────────
+
+/// Arithmetic for the cumulative functions: `None` when an `i64` result overflows.
+/// Float arithmetic never fails.
+pub trait CheckedArith: Sized {
+    fn add_(self, other: Self) -> Option<Self>;
+    fn sub_(self, other: Self) -> Option<Self>;
+    fn mul_(self, other: Self) -> Option<Self>;
+}
+
+impl CheckedArith for i64 {
+    fn add_(self, other: i64) -> Option<i64> {
+        self.checked_add(other)
+    }
+    fn sub_(self, other: i64) -> Option<i64> {
+        self.checked_sub(other)
+    }
+    fn mul_(self, other: i64) -> Option<i64> {
+        self.checked_mul(other)
+    }
+}
+
+impl CheckedArith for f64 {
+    fn add_(self, other: f64) -> Option<f64> {
+        Some(self + other)
+    }
+    fn sub_(self, other: f64) -> Option<f64> {
+        Some(self - other)
+    }
+    fn mul_(self, other: f64) -> Option<f64> {
+        Some(self * other)
+    }
+}
+
+/// Shared driver for the cumulative functions.
+///
+/// With `Omit`, NaN positions keep their NaN in the output but are left out of the
+/// running value. With `Propagate`, the first NaN poisons every later position.
+/// A `step` returning `None` fails with `NumbersError::Overflow`.
+fn accumulate<T, F>(slice: &[T], policy: NanPolicy, step: F) -> Result<Vec<T>, NumbersError>
+where
+    T: PartialOrd + Copy,
+    F: Fn(T, T) -> Option<T>,
+{
+    check_nan(slice, policy)?;
+    let mut result = Vec::with_capacity(slice.len());
+    let mut acc: Option<T> = None;
+    for &x in slice {
+        if is_nan_(&x) {
+            if policy == NanPolicy::Propagate {
+                acc = Some(x);
+            }
+            result.push(x);
+            continue;
+        }
+        let next = match acc {
+            Some(prev) if is_nan_(&prev) => prev,
+            Some(prev) => step(prev, x).ok_or(NumbersError::Overflow)?,
+            None => x,
+        };
+        acc = Some(next);
+        result.push(next);
+    }
+    Ok(result)
+}
+
+pub fn cumsum_<T>(slice: &[T], policy: NanPolicy) -> Result<Vec<T>, NumbersError>
+where
+    T: PartialOrd + Copy + CheckedArith,
+{
+    accumulate(slice, policy, T::add_)
+}
+
+pub fn cumprod_<T>(slice: &[T], policy: NanPolicy) -> Result<Vec<T>, NumbersError>
+where
+    T: PartialOrd + Copy + CheckedArith,
+{
+    accumulate(slice, policy, T::mul_)
+}
+
+pub fn cummax_<T>(slice: &[T], policy: NanPolicy) -> Result<Vec<T>, NumbersError>
+where
+    T: PartialOrd + Copy,
+{
+    accumulate(slice, policy, |acc, x| Some(if x > acc { x } else { acc }))
+}
+
+pub fn cummin_<T>(slice: &[T], policy: NanPolicy) -> Result<Vec<T>, NumbersError>
+where
+    T: PartialOrd + Copy,
+{
+    accumulate(slice, policy, |acc, x| Some(if x < acc { x } else { acc }))
+}
+
+/// Difference between each element and the one `lag` positions before it.
+/// The output is `lag` elements shorter than the input, after `Omit` drops NaN values.
+pub fn diff_<T>(slice: &[T], lag: usize, policy: NanPolicy) -> Result<Vec<T>, NumbersError>
+where
+    T: PartialOrd + Copy + CheckedArith,
+{
+    if lag == 0 {
+        return Err(NumbersError::InvalidArgument(
+            "lag must be at least 1".into(),
+        ));
+    }
+    let slice = apply_nan_policy_(slice, policy)?;
+    slice
+        .iter()
+        .zip(slice.iter().skip(lag))
+        .map(|(&prev, &x)| x.sub_(prev).ok_or(NumbersError::Overflow))
+        .collect()
+}
+
+/// Relative change between each element and the one `lag` positions before it, after
+/// `Omit` drops NaN values. A zero previous value gives ±inf, or NaN when the current
+/// value is zero too, as in pandas.
+pub fn pct_change_(slice: &[f64], lag: usize, policy: NanPolicy) -> Result<Vec<f64>, NumbersError> {
+    if lag == 0 {
+        return Err(NumbersError::InvalidArgument(
+            "lag must be at least 1".into(),
+        ));
+    }
+    let slice = apply_nan_policy_(slice, policy)?;
+    Ok(slice
+        .iter()
+        .zip(slice.iter().skip(lag))
+        .map(|(&prev, &x)| (x - prev) / prev)
+        .collect())
+}
+
+/// Exponentially weighted moving average: `y[t] = alpha * x[t] + (1 - alpha) * y[t - 1]`.
+pub fn ewm_mean_(slice: &[f64], alpha: f64, policy: NanPolicy) -> Result<Vec<f64>, NumbersError> {
+    if !(alpha > 0.0 && alpha <= 1.0) {
+        return Err(NumbersError::InvalidArgument(
+            "alpha must be in the interval (0, 1]".into(),
+        ));
+    }
+    accumulate(slice, policy, |acc, x| {
+        Some(alpha * x + (1.0 - alpha) * acc)
+    })
+}
+
+// ─── Rolling windows ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollingStat {
+    Mean,
+    Sum,
+    Min,
+    Max,
+    /// Sample standard deviation (`ddof = 1`).
+    Std,
+}
+
+impl std::str::FromStr for RollingStat {
+    type Err = NumbersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(RollingStat::Mean),
+            "sum" => Ok(RollingStat::Sum),
+            "min" => Ok(RollingStat::Min),
+            "max" => Ok(RollingStat::Max),
+            "std" => Ok(RollingStat::Std),
+            _ => Err(NumbersError::InvalidArgument(format!(
+                "stat must be 'mean', 'sum', 'min', 'max' or 'std', got '{s}'"
+            ))),
+        }
+    }
+}
+
+/// Sliding-window extreme in O(n) using a monotonic deque of indices.
+/// `keep(a, b)` is true when `a` should evict `b` from the back of the deque.
+fn rolling_extreme<F>(slice: &[f64], window: usize, keep: F) -> Vec<f64>
+where
+    F: Fn(f64, f64) -> bool,
+{
+    let mut deque: std::collections::VecDeque<usize> = std::collections::VecDeque::new();
+    let mut result = Vec::with_capacity(slice.len() + 1 - window);
+    for (i, &x) in slice.iter().enumerate() {
+        if !x.is_nan() {
+            while deque.back().is_some_and(|&j| keep(x, slice[j])) {
+                deque.pop_back();
+            }
+            deque.push_back(i);
+        }
+        if deque.front().is_some_and(|&j| j + window <= i) {
+            deque.pop_front();
+        }
+        if i + 1 >= window {
+            result.push(deque.front().map_or(f64::NAN, |&j| slice[j]));
+        }
+    }
+    result
+}
+
+/// Statistic over every full window of `window` consecutive values.
+/// The output has `len - window + 1` elements (empty when the window is longer than the input).
+///
+/// With `Propagate` a window containing NaN yields NaN; with `Omit` the NaN values are
+/// ignored and a window made only of NaN yields NaN.
+pub fn rolling_(
+    slice: &[f64],
+    window: usize,
+    stat: RollingStat,
+    policy: NanPolicy,
+) -> Result<Vec<f64>, NumbersError> {
+    if window == 0 {
+        return Err(NumbersError::InvalidArgument(
+            "window must be at least 1".into(),
+        ));
+    }
+    check_nan(slice, policy)?;
+    if window > slice.len() {
+        return Ok(Vec::new());
+    }
+
+    let nan_count = |from: usize, to: usize| slice[from..to].iter().filter(|x| x.is_nan()).count();
+    let mut nans = nan_count(0, window - 1);
+
+    match stat {
+        RollingStat::Min | RollingStat::Max => {
+            let mut result = if stat == RollingStat::Min {
+                rolling_extreme(slice, window, |a, b| a <= b)
+            } else {
+                rolling_extreme(slice, window, |a, b| a >= b)
+            };
+            if policy == NanPolicy::Propagate {
+                for (i, value) in result.iter_mut().enumerate() {
+                    nans += slice[i + window - 1].is_nan() as usize;
+                    if nans > 0 {
+                        *value = f64::NAN;
+                    }
+                    nans -= slice[i].is_nan() as usize;
+                }
+            }
+            Ok(result)
+        }
+        RollingStat::Sum | RollingStat::Mean | RollingStat::Std => {
+            let mut moments = WindowMoments::default();
+            for &x in &slice[..window - 1] {
+                moments.add(x);
+            }
+            let mut result = Vec::with_capacity(slice.len() - window + 1);
+            for i in window - 1..slice.len() {
+                let entering = slice[i];
+                moments.add(entering);
+                nans += entering.is_nan() as usize;
+
+                let n = window - nans;
+                let value = if nans > 0 && policy == NanPolicy::Propagate {
+                    f64::NAN
+                } else {
+                    match stat {
+                        RollingStat::Sum if n > 0 => moments.sum(),
+                        RollingStat::Mean if n > 0 => moments.sum() / n as f64,
+                        RollingStat::Std => moments.std(),
+                        _ => f64::NAN,
+                    }
+                };
+                result.push(value);
+
+                let leaving = slice[i + 1 - window];
+                moments.remove(leaving);
+                nans -= leaving.is_nan() as usize;
+            }
+            Ok(result)
+        }
+    }
+}
+
+/// Running sum and variance of the values in a sliding window.
+///
+/// The sum is compensated (Neumaier) and the variance uses Welford updates, so values
+/// leaving the window do not leave rounding error behind. Infinities are counted
+/// rather than added, so one infinity only affects the windows that contain it.
+/// NaN values are ignored; callers apply the NaN policy.
+#[derive(Default)]
+struct WindowMoments {
+    /// Number of finite values.
+    n: usize,
+    sum: f64,
+    compensation: f64,
+    mean: f64,
+    m2: f64,
+    pos_inf: usize,
+    neg_inf: usize,
+}
+
+impl WindowMoments {
+    fn add_to_sum(&mut self, x: f64) {
+        let t = self.sum + x;
+        self.compensation += if self.sum.abs() >= x.abs() {
+            (self.sum - t) + x
+        } else {
+            (x - t) + self.sum
+        };
+        self.sum = t;
+    }
+
+    fn add(&mut self, x: f64) {
+        match x {
+            f64::INFINITY => self.pos_inf += 1,
+            f64::NEG_INFINITY => self.neg_inf += 1,
+            _ if x.is_nan() => {}
+            _ => {
+                self.n += 1;
+                self.add_to_sum(x);
+                let delta = x - self.mean;
+                self.mean += delta / self.n as f64;
+                self.m2 += delta * (x - self.mean);
+            }
+        }
+    }
+
+    fn remove(&mut self, x: f64) {
+        match x {
+            f64::INFINITY => self.pos_inf -= 1,
+            f64::NEG_INFINITY => self.neg_inf -= 1,
+            _ if x.is_nan() => {}
+            _ if self.n == 1 => {
+                *self = WindowMoments {
+                    pos_inf: self.pos_inf,
+                    neg_inf: self.neg_inf,
+                    ..WindowMoments::default()
+                }
+            }
+            _ => {
+                self.n -= 1;
+                self.add_to_sum(-x);
+                let delta = x - self.mean;
+                self.mean -= delta / self.n as f64;
+                self.m2 -= delta * (x - self.mean);
+            }
+        }
+    }
+
+    fn sum(&self) -> f64 {
+        match (self.pos_inf > 0, self.neg_inf > 0) {
+            (true, true) => f64::NAN,
+            (true, false) => f64::INFINITY,
+            (false, true) => f64::NEG_INFINITY,
+            (false, false) => self.sum + self.compensation,
+        }
+    }
+
+    /// Sample standard deviation (`ddof = 1`); NaN with fewer than two values or any
+    /// infinity.
+    fn std(&self) -> f64 {
+        if self.pos_inf + self.neg_inf > 0 || self.n < 2 {
+            return f64::NAN;
+        }
+        (self.m2.max(0.0) / (self.n - 1) as f64).sqrt()
+    }
+}
+
+// ─── Paired statistics ───────────────────────────────────────────────────────
+
+/// Convert two paired series to floats, checking they match and hold at least two points.
+fn paired<T, U>(xs: &[T], ys: &[U]) -> Result<(Vec<f64>, Vec<f64>), NumbersError>
+where
+    T: std::convert::Into<f64> + Copy,
+    U: std::convert::Into<f64> + Copy,
+{
+    if xs.len() != ys.len() {
+        return Err(NumbersError::InvalidArgument(format!(
+            "xs and ys must have the same length ({} != {})",
+            xs.len(),
+            ys.len()
+        )));
+    }
+    if xs.len() < 2 {
+        return Err(NumbersError::InvalidArgument(
+            "at least two data points are required".into(),
+        ));
+    }
+    Ok((
+        xs.iter().map(|&x| x.into()).collect(),
+        ys.iter().map(|&y| y.into()).collect(),
+    ))
+}
+
+/// Sums of squared deviations and cross deviations: (Sxx, Syy, Sxy).
+fn deviation_sums(xs: &[f64], ys: &[f64]) -> (f64, f64, f64) {
+    let n = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = ys.iter().sum::<f64>() / n;
+    xs.iter()
+        .zip(ys)
+        .fold((0.0, 0.0, 0.0), |(sxx, syy, sxy), (&x, &y)| {
+            let (dx, dy) = (x - mean_x, y - mean_y);
+            (sxx + dx * dx, syy + dy * dy, sxy + dx * dy)
+        })
+}
+
+/// Sample covariance (`ddof = 1`) of two paired series.
+pub fn covariance_<T, U>(xs: &[T], ys: &[U]) -> Result<f64, NumbersError>
+where
+    T: std::convert::Into<f64> + Copy,
+    U: std::convert::Into<f64> + Copy,
+{
+    let (xs, ys) = paired(xs, ys)?;
+    let (_, _, sxy) = deviation_sums(&xs, &ys);
+    Ok(sxy / (xs.len() - 1) as f64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrelationMethod {
+    Pearson,
+    /// Pearson correlation of the ranks, with ties sharing their average rank.
+    Spearman,
+    /// Kendall's tau-b, which accounts for ties in either series.
+    Kendall,
+}
+
+impl std::str::FromStr for CorrelationMethod {
+    type Err = NumbersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pearson" => Ok(CorrelationMethod::Pearson),
+            "spearman" => Ok(CorrelationMethod::Spearman),
+            "kendall" => Ok(CorrelationMethod::Kendall),
+            _ => Err(NumbersError::InvalidArgument(format!(
+                "method must be 'pearson', 'spearman' or 'kendall', got '{s}'"
+            ))),
+        }
+    }
+}
+
+/// 1-based ranks, with tied values sharing the average of their ranks.
+fn average_ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &i in &order[start..end] {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+fn pearson(xs: &[f64], ys: &[f64]) -> Result<f64, NumbersError> {
+    let (sxx, syy, sxy) = deviation_sums(xs, ys);
+    if sxx == 0.0 || syy == 0.0 {
+        return Err(NumbersError::InvalidArgument(
+            "correlation is undefined for constant input".into(),
+        ));
+    }
+    Ok(sxy / (sxx * syy).sqrt())
+}
+
+fn kendall(xs: &[f64], ys: &[f64]) -> Result<f64, NumbersError> {
+    let (mut concordant, mut discordant) = (0i64, 0i64);
+    let (mut ties_x, mut ties_y) = (0i64, 0i64);
+    for i in 0..xs.len() {
+        for j in i + 1..xs.len() {
+            let dx = xs[i].partial_cmp(&xs[j]);
+            let dy = ys[i].partial_cmp(&ys[j]);
+            match (dx, dy) {
+                (Some(std::cmp::Ordering::Equal), Some(std::cmp::Ordering::Equal)) => {}
+                (Some(std::cmp::Ordering::Equal), _) => ties_x += 1,
+                (_, Some(std::cmp::Ordering::Equal)) => ties_y += 1,
+                (Some(a), Some(b)) if a == b => concordant += 1,
+                (Some(_), Some(_)) => discordant += 1,
+                _ => return Ok(f64::NAN),
+            }
+        }
+    }
+    let denominator =
+        (((concordant + discordant + ties_x) * (concordant + discordant + ties_y)) as f64).sqrt();
+    if denominator == 0.0 {
+        return Err(NumbersError::InvalidArgument(
+            "correlation is undefined for constant input".into(),
+        ));
+    }
+    Ok((concordant - discordant) as f64 / denominator)
+}
+
+/// Correlation coefficient of two paired series, in `[-1, 1]`. NaN in either series
+/// gives NaN for every method.
+pub fn correlation_<T, U>(
+    xs: &[T],
+    ys: &[U],
+    method: CorrelationMethod,
+) -> Result<f64, NumbersError>
+where
+    T: std::convert::Into<f64> + Copy,
+    U: std::convert::Into<f64> + Copy,
+{
+    let (xs, ys) = paired(xs, ys)?;
+    match method {
+        CorrelationMethod::Pearson => pearson(&xs, &ys),
+        // Ranking would order NaN as the largest value instead of propagating it.
+        CorrelationMethod::Spearman if xs.iter().chain(&ys).any(|x| x.is_nan()) => Ok(f64::NAN),
+        CorrelationMethod::Spearman => pearson(&average_ranks(&xs), &average_ranks(&ys)),
+        CorrelationMethod::Kendall => kendall(&xs, &ys),
+    }
+}
+
+/// Result of an ordinary least squares fit `y = slope * x + intercept`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearRegression {
+    pub slope: f64,
+    pub intercept: f64,
+    /// Coefficient of determination; 1.0 when `ys` is constant and fitted exactly.
+    pub r_squared: f64,
+}
+
+/// Ordinary least squares fit of `ys` against `xs`.
+pub fn linear_regression_<T, U>(xs: &[T], ys: &[U]) -> Result<LinearRegression, NumbersError>
+where
+    T: std::convert::Into<f64> + Copy,
+    U: std::convert::Into<f64> + Copy,
+{
+    let (xs, ys) = paired(xs, ys)?;
+    let (sxx, syy, sxy) = deviation_sums(&xs, &ys);
+    if sxx == 0.0 {
+        return Err(NumbersError::InvalidArgument(
+            "linear regression is undefined when all xs are equal".into(),
+        ));
+    }
+    let n = xs.len() as f64;
+    let slope = sxy / sxx;
+    let intercept = ys.iter().sum::<f64>() / n - slope * xs.iter().sum::<f64>() / n;
+    let r_squared = if syy == 0.0 {
+        1.0
+    } else {
+        sxy * sxy / (sxx * syy)
+    };
+    Ok(LinearRegression {
+        slope,
+        intercept,
+        r_squared,
+    })
+}
+
+// ─── Histograms ──────────────────────────────────────────────────────────────
+
+/// How `histogram_` chooses its bins.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistogramBins {
+    /// This many equal-width bins over the range.
+    Count(usize),
+    /// Explicit bin edges, which must be strictly increasing.
+    Edges(Vec<f64>),
+    /// The narrower of Sturges and Freedman-Diaconis (Sturges when the IQR is zero).
+    Auto,
+    /// `log2(n) + 1` bins.
+    Sturges,
+    /// Bin width of `2 * IQR / cbrt(n)`.
+    FreedmanDiaconis,
+}
+
+impl std::str::FromStr for HistogramBins {
+    type Err = NumbersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(HistogramBins::Auto),
+            "sturges" => Ok(HistogramBins::Sturges),
+            "fd" => Ok(HistogramBins::FreedmanDiaconis),
+            _ => Err(NumbersError::InvalidArgument(format!(
+                "bins must be 'auto', 'sturges' or 'fd', got '{s}'"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Number of values in each bin; one fewer entry than `edges`.
+    pub counts: Vec<usize>,
+    pub edges: Vec<f64>,
+}
+
+/// Linear-interpolated quantile of an already sorted, non-empty slice.
+fn quantile_sorted(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// Median (midpoint of the two middle values for even lengths); NaN if any value is NaN.
+pub fn median_(values: &[f64]) -> Option<f64> {
+    if values.iter().any(|x| x.is_nan()) {
+        return Some(f64::NAN);
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    (!sorted.is_empty()).then(|| quantile_sorted(&sorted, 0.5))
+}
+
+/// Sample variance (`ddof = 1`); `None` for fewer than two values.
+pub fn variance_(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    Some(values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0))
+}
+
+fn check_sorted(values: &[f64], name: &str, strict: bool) -> Result<(), NumbersError> {
+    let sorted = values
+        .windows(2)
+        .all(|w| if strict { w[0] < w[1] } else { w[0] <= w[1] });
+    if !sorted {
+        return Err(NumbersError::InvalidArgument(format!(
+            "{name} must be sorted in increasing order"
+        )));
+    }
+    Ok(())
+}
+
+/// Most bins `histogram_` will build. A near-zero IQR with a distant outlier can make
+/// Freedman-Diaconis ask for billions of bins.
+pub const MAX_HISTOGRAM_BINS: usize = 1 << 20;
+
+fn too_many_bins(count: f64) -> NumbersError {
+    NumbersError::InvalidArgument(format!(
+        "{count} bins requested, more than the maximum of {MAX_HISTOGRAM_BINS}"
+    ))
+}
+
+/// Number of bins picked by one of the automatic methods for `data` spanning `span`.
+/// `Auto` falls back to Sturges when Freedman-Diaconis exceeds `MAX_HISTOGRAM_BINS`;
+/// `FreedmanDiaconis` fails instead.
+fn auto_bin_count(data: &[f64], span: f64, bins: &HistogramBins) -> Result<usize, NumbersError> {
+    let n = data.len() as f64;
+    if data.len() < 2 || span == 0.0 {
+        return Ok(1);
+    }
+    let sturges_width = span / (n.log2() + 1.0);
+    let fd_width = {
+        let mut sorted = data.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let iqr = quantile_sorted(&sorted, 0.75) - quantile_sorted(&sorted, 0.25);
+        2.0 * iqr / n.cbrt()
+    };
+    let fd_count = (span / fd_width).ceil();
+    let fd_fits = fd_width > 0.0 && fd_count <= MAX_HISTOGRAM_BINS as f64;
+    let width = match bins {
+        HistogramBins::Sturges => sturges_width,
+        HistogramBins::FreedmanDiaconis if fd_fits => fd_width,
+        HistogramBins::FreedmanDiaconis if fd_width > 0.0 => return Err(too_many_bins(fd_count)),
+        HistogramBins::FreedmanDiaconis => return Ok(1),
+        _ if fd_fits => fd_width.min(sturges_width),
+        _ => sturges_width,
+    };
+    Ok(((span / width).ceil() as usize).max(1))
+}
+
+/// Count `values` into bins. Values outside the range and NaN are ignored; the last
+/// bin includes its right edge. `range` defaults to the min and max of the finite values.
+pub fn histogram_(
+    values: &[f64],
+    bins: HistogramBins,
+    range: Option<(f64, f64)>,
+) -> Result<Histogram, NumbersError> {
+    if let Some((lower, upper)) = range {
+        if !lower.is_finite() || !upper.is_finite() || lower > upper {
+            return Err(NumbersError::InvalidArgument(
+                "range must be finite and satisfy lower <= upper".into(),
+            ));
+        }
+    }
+
+    let edges = match bins {
+        HistogramBins::Edges(edges) => {
+            if edges.len() < 2 {
+                return Err(NumbersError::InvalidArgument(
+                    "at least two bin edges are required".into(),
+                ));
+            }
+            check_sorted(&edges, "edges", true)?;
+            edges
+        }
+        bins => {
+            let finite: Vec<f64> = values.iter().copied().filter(|x| x.is_finite()).collect();
+            let (mut lower, mut upper) =
+                range.unwrap_or_else(|| match (min_(&finite), max_(&finite)) {
+                    (Some(lo), Some(hi)) => (lo, hi),
+                    _ => (0.0, 1.0),
+                });
+            let data: Vec<f64> = finite
+                .into_iter()
+                .filter(|&x| x >= lower && x <= upper)
+                .collect();
+            let count = match bins {
+                HistogramBins::Count(0) => {
+                    return Err(NumbersError::InvalidArgument(
+                        "bins must be at least 1".into(),
+                    ))
+                }
+                HistogramBins::Count(count) if count > MAX_HISTOGRAM_BINS => {
+                    return Err(too_many_bins(count as f64))
+                }
+                HistogramBins::Count(count) => count,
+                ref method => auto_bin_count(&data, upper - lower, method)?,
+            };
+            if lower == upper {
+                lower -= 0.5;
+                upper += 0.5;
+            }
+            (0..=count)
+                .map(|i| lower + (upper - lower) * i as f64 / count as f64)
+                .collect()
+        }
+    };
+
+    let (first, last) = (edges[0], edges[edges.len() - 1]);
+    let mut counts = vec![0; edges.len() - 1];
+    for &x in values {
+        if x.is_nan() || x < first || x > last {
+            continue;
+        }
+        let bin = edges.partition_point(|&e| e <= x).min(counts.len());
+        if let Some(bin) = bin.checked_sub(1) {
+            counts[bin] += 1;
+        }
+    }
+    Ok(Histogram { counts, edges })
+}
+
+/// Index of the bin each value falls into: `i` such that `edges[i - 1] <= x < edges[i]`.
+/// Values below the first edge get 0, values at or above the last edge (and NaN) get `edges.len()`.
+pub fn digitize_(values: &[f64], edges: &[f64]) -> Result<Vec<usize>, NumbersError> {
+    check_sorted(edges, "edges", false)?;
+    Ok(values
+        .iter()
+        .map(|&x| {
+            if x.is_nan() {
+                edges.len()
+            } else {
+                edges.partition_point(|&e| e <= x)
+            }
+        })
+        .collect())
+}
+
+// ─── Scaling ─────────────────────────────────────────────────────────────────
+
+/// Map every value to `(x - center) / spread`. NaN values stay NaN.
+///
+/// A zero (or undefined) `spread` would turn every value into NaN or infinity, so it
+/// yields `fill` for every non-NaN value instead, or an error when no fill is given.
+fn scale_by(
+    values: &[f64],
+    center: f64,
+    spread: f64,
+    fill: Option<f64>,
+    what: &str,
+) -> Result<Vec<f64>, NumbersError> {
+    if spread == 0.0 || spread.is_nan() {
+        return match fill {
+            Some(fill) => Ok(values
+                .iter()
+                .map(|&x| if x.is_nan() { x } else { fill })
+                .collect()),
+            None => Err(NumbersError::InvalidArgument(format!(
+                "{what} is zero, pass a fill value to scale constant input"
+            ))),
+        };
+    }
+    Ok(values.iter().map(|&x| (x - center) / spread).collect())
+}
+
+/// The non-NaN values, or `None` when there are none (the input is returned unchanged then).
+fn finite_part(values: &[f64]) -> Option<Vec<f64>> {
+    let present: Vec<f64> = values.iter().copied().filter(|x| !x.is_nan()).collect();
+    (!present.is_empty()).then_some(present)
+}
+
+/// Min-max scaling to `[0, 1]`. Statistics ignore NaN values, which stay NaN.
+pub fn normalize_(values: &[f64], fill: Option<f64>) -> Result<Vec<f64>, NumbersError> {
+    rescale_(values, 0.0, 1.0, fill)
+}
+
+/// Min-max scaling to `[new_min, new_max]`. Statistics ignore NaN values, which stay NaN.
+pub fn rescale_(
+    values: &[f64],
+    new_min: f64,
+    new_max: f64,
+    fill: Option<f64>,
+) -> Result<Vec<f64>, NumbersError> {
+    let Some(present) = finite_part(values) else {
+        return Ok(values.to_vec());
+    };
+    let (lo, hi) = present
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| {
+            (lo.min(x), hi.max(x))
+        });
+    let unit = scale_by(values, lo, hi - lo, fill, "range")?;
+    if fill.is_some() && hi == lo {
+        return Ok(unit);
+    }
+    Ok(unit
+        .into_iter()
+        .map(|x| new_min + x * (new_max - new_min))
+        .collect())
+}
+
+/// Z-score: `(x - mean) / std` with the sample standard deviation (`ddof = 1`, like
+/// `rolling(..., "std")`). Statistics ignore NaN values, which stay NaN.
+pub fn standardize_(values: &[f64], fill: Option<f64>) -> Result<Vec<f64>, NumbersError> {
+    let Some(present) = finite_part(values) else {
+        return Ok(values.to_vec());
+    };
+    let mean = present.iter().sum::<f64>() / present.len() as f64;
+    let std = variance_(&present).map_or(0.0, f64::sqrt);
+    scale_by(values, mean, std, fill, "standard deviation")
+}
+
+/// `(x - median) / IQR`, which outliers barely affect. Statistics ignore NaN values,
+/// which stay NaN.
+pub fn robust_scale_(values: &[f64], fill: Option<f64>) -> Result<Vec<f64>, NumbersError> {
+    let Some(mut sorted) = finite_part(values) else {
+        return Ok(values.to_vec());
+    };
+    sorted.sort_by(f64::total_cmp);
+    let median = quantile_sorted(&sorted, 0.5);
+    let iqr = quantile_sorted(&sorted, 0.75) - quantile_sorted(&sorted, 0.25);
+    scale_by(values, median, iqr, fill, "interquartile range")
+}
+
+// ─── Selection ───────────────────────────────────────────────────────────────
+
+/// A numeric key that keeps integers exact. Ints and floats compare by value, without
+/// converting large ints to `f64`; NaN is unordered as usual.
+#[derive(Debug, Clone, Copy)]
+pub enum ExactNumber {
+    Int(i128),
+    Float(f64),
+}
+
+/// Exact order of an integer and a float.
+fn cmp_int_float(i: i128, x: f64) -> Option<std::cmp::Ordering> {
+    // Rounding to the nearest float is monotonic, so a strict order survives it; only
+    // a tie needs a closer look, and then `x` is an integer.
+    match (i as f64).partial_cmp(&x)? {
+        std::cmp::Ordering::Equal if x >= i128::MAX as f64 => Some(std::cmp::Ordering::Less),
+        std::cmp::Ordering::Equal => Some(i.cmp(&(x as i128))),
+        ord => Some(ord),
+    }
+}
+
+impl PartialOrd for ExactNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (*self, *other) {
+            (ExactNumber::Int(a), ExactNumber::Int(b)) => Some(a.cmp(&b)),
+            (ExactNumber::Float(a), ExactNumber::Float(b)) => a.partial_cmp(&b),
+            (ExactNumber::Int(a), ExactNumber::Float(b)) => cmp_int_float(a, b),
+            (ExactNumber::Float(a), ExactNumber::Int(b)) => {
+                cmp_int_float(b, a).map(|o| o.reverse())
+            }
+        }
+    }
+}
+
+impl PartialEq for ExactNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(std::cmp::Ordering::Equal)
+    }
+}
+
+/// Heap entry ordered so that the worst of the kept candidates sits on top.
+struct Candidate<T> {
+    key: T,
+    index: usize,
+    largest: bool,
+}
+
+impl<T: PartialOrd> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // NaN keys never reach the heap, so `partial_cmp` always succeeds.
+        let by_key = self
+            .key
+            .partial_cmp(&other.key)
+            .unwrap_or(std::cmp::Ordering::Equal);
+        let by_key = if self.largest {
+            by_key.reverse()
+        } else {
+            by_key
+        };
+        // On equal keys the later element is worse, which keeps ties in input order.
+        by_key.then(self.index.cmp(&other.index))
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for Candidate<T> {}
+
+/// Indices of the `k` largest (or smallest) values, best first, using a heap bounded
+/// at `k` entries instead of a full sort. Equal values keep their input order and NaN
+/// values are never selected.
+pub fn top_k_<T>(slice: &[T], k: usize, largest: bool) -> Vec<usize>
+where
+    T: PartialOrd + Copy,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut heap = std::collections::BinaryHeap::with_capacity(k.min(slice.len()));
+    for (index, &key) in slice.iter().enumerate() {
+        if is_nan_(&key) {
+            continue;
+        }
+        let candidate = Candidate {
+            key,
+            index,
+            largest,
+        };
+        if heap.len() < k {
+            heap.push(candidate);
+        } else if heap.peek().is_some_and(|worst| candidate < *worst) {
+            heap.pop();
+            heap.push(candidate);
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|c| c.index)
+        .collect()
+}
+
+// ─── Integer math ────────────────────────────────────────────────────────────
+// Results that do not fit in `i64` come back as `None`, so callers can switch to
+// arbitrary-precision arithmetic instead of overflowing.
+
+fn gcd_pair(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Greatest common divisor of all values (always non-negative); 0 for an empty slice.
+pub fn gcd_(values: &[i64]) -> Option<i64> {
+    let gcd = values
+        .iter()
+        .fold(0, |acc, x| gcd_pair(acc, x.unsigned_abs()));
+    i64::try_from(gcd).ok()
+}
+
+/// Least common multiple of all values (always non-negative); 1 for an empty slice,
+/// 0 when any value is 0.
+pub fn lcm_(values: &[i64]) -> Option<i64> {
+    let mut lcm: u64 = 1;
+    for x in values {
+        let x = x.unsigned_abs();
+        if x == 0 {
+            return Some(0);
+        }
+        lcm = (lcm / gcd_pair(lcm, x)).checked_mul(x)?;
+    }
+    i64::try_from(lcm).ok()
+}
+
+/// `n!`, or `None` once it exceeds `i64` (from `21!` on).
+pub fn factorial_(n: u64) -> Option<i64> {
+    (2..=n).try_fold(1i64, |acc, x| acc.checked_mul(x as i64))
+}
+
+/// Number of ways to choose `k` items out of `n`; 0 when `k > n`.
+pub fn n_choose_k_(n: u64, k: u64) -> Option<i64> {
+    if k > n {
+        return Some(0);
+    }
+    // C(n, i) grows with i up to n / 2, so checking each step catches overflow exactly.
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        result = result * (n - i) as u128 / (i + 1) as u128;
+        if result > i64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as i64)
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// Square-and-multiply modular exponentiation; `m` must be non-zero.
+fn pow_mod(base: u64, exp: u64, m: u64) -> u64 {
+    let (mut base, mut exp) = (base % m, exp);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// `base^exp mod modulus`, in `[0, modulus)`. Never overflows.
+pub fn mod_pow_(base: i64, exp: u64, modulus: i64) -> Result<i64, NumbersError> {
+    if modulus <= 0 {
+        return Err(NumbersError::InvalidArgument(
+            "modulus must be positive".into(),
+        ));
+    }
+    let base = base.rem_euclid(modulus) as u64;
+    Ok(pow_mod(base, exp, modulus as u64) as i64)
+}
+
+/// Deterministic Miller-Rabin; these bases are exact for every 64-bit integer.
+pub fn is_prime_(n: i64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let n = n as u64;
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    for p in BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    // n - 1 = d * 2^s with d odd.
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for a in BASES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// All primes `<= n`, by the sieve of Eratosthenes. Fails when the sieve, one byte per
+/// number, cannot be allocated.
+pub fn primes_up_to_(n: usize) -> Result<Vec<i64>, NumbersError> {
+    if n < 2 {
+        return Ok(Vec::new());
+    }
+    let mut composite = with_capacity(n.saturating_add(1))?;
+    composite.resize(n + 1, false);
+    let mut i = 2;
+    while i * i <= n {
+        if !composite[i] {
+            for j in (i * i..=n).step_by(i) {
+                composite[j] = true;
+            }
+        }
+        i += 1;
+    }
+    Ok((2..=n)
+        .filter(|&i| !composite[i])
+        .map(|i| i as i64)
+        .collect())
+}
+
+// ─── Interpolation ───────────────────────────────────────────────────────────
+
+/// Linear interpolation between `a` and `b`: `a` at `t = 0`, `b` at `t = 1`.
+/// `t` outside `[0, 1]` extrapolates.
+pub fn lerp_(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// One-dimensional linear interpolation of the points `(xp, fp)` at each `x`, like
+/// `numpy.interp`: `xp` must be increasing, and `x` outside it takes the end values.
+pub fn interp_(x: &[f64], xp: &[f64], fp: &[f64]) -> Result<Vec<f64>, NumbersError> {
+    if xp.len() != fp.len() {
+        return Err(NumbersError::InvalidArgument(
+            "xp and fp must have the same length".into(),
+        ));
+    }
+    if xp.is_empty() {
+        return Err(NumbersError::InvalidArgument(
+            "xp and fp must not be empty".into(),
+        ));
+    }
+    if xp.iter().any(|v| v.is_nan()) {
+        return Err(NumbersError::NanFound);
+    }
+    check_sorted(xp, "xp", false)?;
+    let last = xp.len() - 1;
+    Ok(x.iter()
+        .map(|&x| {
+            if x.is_nan() {
+                return f64::NAN;
+            }
+            // First point strictly to the right of `x`.
+            let right = xp.partition_point(|&p| p <= x);
+            if right == 0 {
+                fp[0]
+            } else if right > last {
+                fp[last]
+            } else {
+                let left = right - 1;
+                let t = (x - xp[left]) / (xp[right] - xp[left]);
+                lerp_(fp[left], fp[right], t)
+            }
+        })
+        .collect())
+}
+
+/// How `fill_gaps_` fills missing entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillMethod {
+    /// Interpolate linearly between the known neighbours.
+    Linear,
+    /// Carry the last known value forward.
+    Ffill,
+    /// Carry the next known value backward.
+    Bfill,
+}
+
+impl std::str::FromStr for FillMethod {
+    type Err = NumbersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear" => Ok(FillMethod::Linear),
+            "ffill" => Ok(FillMethod::Ffill),
+            "bfill" => Ok(FillMethod::Bfill),
+            _ => Err(NumbersError::InvalidArgument(format!(
+                "method must be 'linear', 'ffill' or 'bfill', got '{s}'"
+            ))),
+        }
+    }
+}
+
+/// Fill `None` entries from their neighbours. Gaps with no neighbour on the needed side
+/// (leading gaps for `Ffill`, trailing for `Bfill`, either for `Linear`) stay `None`.
+pub fn fill_gaps_(values: &[Option<f64>], method: FillMethod) -> Vec<Option<f64>> {
+    let mut filled = values.to_vec();
+    match method {
+        FillMethod::Ffill => {
+            for i in 1..filled.len() {
+                if filled[i].is_none() {
+                    filled[i] = filled[i - 1];
+                }
+            }
+        }
+        FillMethod::Bfill => {
+            for i in (0..filled.len().saturating_sub(1)).rev() {
+                if filled[i].is_none() {
+                    filled[i] = filled[i + 1];
+                }
+            }
+        }
+        FillMethod::Linear => {
+            let mut previous: Option<(usize, f64)> = None;
+            for (i, value) in values.iter().enumerate() {
+                let Some(value) = *value else {
+                    continue;
+                };
+                if let Some((start, from)) = previous {
+                    let span = (i - start) as f64;
+                    for (j, slot) in filled.iter_mut().enumerate().take(i).skip(start + 1) {
+                        *slot = Some(lerp_(from, value, (j - start) as f64 / span));
+                    }
+                }
+                previous = Some((i, value));
+            }
+        }
+    }
+    filled
+}
+
+/// How `resample_` combines the values that fall into one bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResampleAgg {
+    Mean,
+    Sum,
+    Min,
+    Max,
+    Count,
+    First,
+    Last,
+    Median,
+}
+
+impl std::str::FromStr for ResampleAgg {
+    type Err = NumbersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(ResampleAgg::Mean),
+            "sum" => Ok(ResampleAgg::Sum),
+            "min" => Ok(ResampleAgg::Min),
+            "max" => Ok(ResampleAgg::Max),
+            "count" => Ok(ResampleAgg::Count),
+            "first" => Ok(ResampleAgg::First),
+            "last" => Ok(ResampleAgg::Last),
+            "median" => Ok(ResampleAgg::Median),
+            _ => Err(NumbersError::InvalidArgument(format!(
+                "agg must be 'mean', 'sum', 'min', 'max', 'count', 'first', 'last' or 'median', got '{s}'"
+            ))),
+        }
+    }
+}
+
+impl ResampleAgg {
+    /// Combine one bucket's values (in timestamp order). Empty buckets give `None`,
+    /// except for `Sum` and `Count`, which give 0.
+    fn apply(self, bucket: &[f64]) -> Option<f64> {
+        match self {
+            ResampleAgg::Sum => Some(bucket.iter().sum()),
+            ResampleAgg::Count => Some(bucket.len() as f64),
+            ResampleAgg::Mean => mean_(bucket),
+            ResampleAgg::Min => min_(bucket),
+            ResampleAgg::Max => max_(bucket),
+            ResampleAgg::First => bucket.first().copied(),
+            ResampleAgg::Last => bucket.last().copied(),
+            ResampleAgg::Median => median_(bucket),
+        }
+    }
+}
+
+/// Most buckets `resample_` will return. Past this the step is almost certainly too
+/// small for the time span, or a stray timestamp is far from the others.
+pub const MAX_RESAMPLE_BUCKETS: usize = 1 << 24;
+
+/// Timestamps `resample_` can bucket. Integer timestamps are bucketed in `i64`, so
+/// nanosecond epochs keep their precision.
+pub trait ResampleTime: Copy + PartialOrd {
+    /// Whether `step` is a usable bucket width.
+    fn valid_step(step: Self) -> bool;
+    fn is_finite(self) -> bool;
+    /// Index of the bucket of width `step` holding `self`, or `None` when it does not
+    /// fit in `i64`.
+    fn bucket(self, step: Self) -> Option<i64>;
+    /// Start of bucket `index`, or `None` when it is not representable.
+    fn bucket_start(index: i64, step: Self) -> Option<Self>;
+}
+
+impl ResampleTime for i64 {
+    fn valid_step(step: i64) -> bool {
+        step > 0
+    }
+    fn is_finite(self) -> bool {
+        true
+    }
+    fn bucket(self, step: i64) -> Option<i64> {
+        Some(self.div_euclid(step))
+    }
+    fn bucket_start(index: i64, step: i64) -> Option<i64> {
+        index.checked_mul(step)
+    }
+}
+
+impl ResampleTime for f64 {
+    fn valid_step(step: f64) -> bool {
+        step.is_finite() && step > 0.0
+    }
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+    fn bucket(self, step: f64) -> Option<i64> {
+        let index = (self / step).floor();
+        (index.abs() < i64::MAX as f64).then_some(index as i64)
+    }
+    fn bucket_start(index: i64, step: f64) -> Option<f64> {
+        Some(index as f64 * step)
+    }
+}
+
+/// Aggregate `(timestamp, value)` points into fixed buckets of width `step`, aligned to
+/// multiples of `step`. Every bucket from the first point to the last is returned as
+/// `(bucket start, aggregate)`, so gaps in the series show up as empty buckets.
+/// Fails when that would be more than `MAX_RESAMPLE_BUCKETS` buckets.
+pub fn resample_<T: ResampleTime>(
+    points: &[(T, f64)],
+    step: T,
+    agg: ResampleAgg,
+) -> Result<Vec<(T, Option<f64>)>, NumbersError> {
+    if !T::valid_step(step) {
+        return Err(NumbersError::InvalidArgument(
+            "step must be a positive number".into(),
+        ));
+    }
+    if points.iter().any(|(t, _)| !t.is_finite()) {
+        return Err(NumbersError::InvalidArgument(
+            "timestamps must be finite".into(),
+        ));
+    }
+    let out_of_range = || NumbersError::InvalidArgument("timestamps are out of range".into());
+    let mut sorted = points.to_vec();
+    // Stable, so `first` and `last` respect input order among equal timestamps.
+    sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Only the occupied buckets are stored, in order; empty ones are filled in below.
+    let mut occupied: Vec<(i64, Vec<f64>)> = Vec::new();
+    for &(t, value) in &sorted {
+        let bucket = t.bucket(step).ok_or_else(out_of_range)?;
+        match occupied.last_mut() {
+            Some((last, values)) if *last == bucket => values.push(value),
+            _ => occupied.push((bucket, vec![value])),
+        }
+    }
+    let (Some(&(first, _)), Some(&(last, _))) = (occupied.first(), occupied.last()) else {
+        return Ok(Vec::new());
+    };
+    let buckets = last as i128 - first as i128 + 1;
+    if buckets > MAX_RESAMPLE_BUCKETS as i128 {
+        return Err(NumbersError::InvalidArgument(format!(
+            "resampling would produce {buckets} buckets, more than the maximum of \
+             {MAX_RESAMPLE_BUCKETS}; use a larger step"
+        )));
+    }
+
+    let mut result = Vec::with_capacity(buckets as usize);
+    let mut occupied = occupied.into_iter().peekable();
+    for index in first..=last {
+        let start = T::bucket_start(index, step).ok_or_else(out_of_range)?;
+        let value = match occupied.next_if(|(bucket, _)| *bucket == index) {
+            Some((_, values)) => agg.apply(&values),
+            None => agg.apply(&[]),
+        };
+        result.push((start, value));
+    }
+    Ok(result)
+}
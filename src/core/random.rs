@@ -0,0 +1,192 @@
+use crate::core::numbers::NumbersError;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+// ─── Generator ───────────────────────────────────────────────────────────────
+
+/// Seedable xoshiro256** generator.
+///
+/// Only integer arithmetic on `u64` is involved, so a given seed yields the same
+/// stream on every platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+/// SplitMix64 step, used to expand a single seed into the generator state.
+fn splitmix64(x: &mut u64) -> u64 {
+    *x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        Rng {
+            state: [
+                splitmix64(&mut x),
+                splitmix64(&mut x),
+                splitmix64(&mut x),
+                splitmix64(&mut x),
+            ],
+        }
+    }
+
+    /// Generator seeded from the per-process hasher keys, for unseeded calls.
+    pub fn from_entropy() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(0);
+        Rng::new(hasher.finish())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Uniform float in `[0, 1)` built from the top 53 bits.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform integer in `[0, n)` without modulo bias (Lemire's method). `n` must be non-zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let m = self.next_u64() as u128 * n as u128;
+            if m as u64 >= threshold {
+                return (m >> 64) as u64;
+            }
+        }
+    }
+
+    /// Uniform integer in `[lower, upper]`, both inclusive.
+    pub fn int_between(&mut self, lower: i64, upper: i64) -> i64 {
+        let (lower, upper) = if lower <= upper {
+            (lower, upper)
+        } else {
+            (upper, lower)
+        };
+        let span = upper.wrapping_sub(lower) as u64;
+        let offset = match span.checked_add(1) {
+            Some(n) => self.below(n),
+            None => self.next_u64(),
+        };
+        lower.wrapping_add(offset as i64)
+    }
+
+    /// Uniform float in `[lower, upper)`.
+    pub fn float_between(&mut self, lower: f64, upper: f64) -> f64 {
+        let (lower, upper) = if lower <= upper {
+            (lower, upper)
+        } else {
+            (upper, lower)
+        };
+        lower + self.next_f64() * (upper - lower)
+    }
+}
+
+// ─── Sampling ────────────────────────────────────────────────────────────────
+
+/// Fisher–Yates shuffle in place.
+pub fn shuffle_<T>(rng: &mut Rng, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        let j = rng.below(i as u64 + 1) as usize;
+        items.swap(i, j);
+    }
+}
+
+/// Index of one uniformly chosen element, or `None` for an empty collection.
+pub fn sample_<T>(rng: &mut Rng, items: &[T]) -> Option<usize> {
+    (!items.is_empty()).then(|| rng.below(items.len() as u64) as usize)
+}
+
+/// Indices of `n` distinct elements chosen without replacement, in random order.
+/// `n` is capped at the collection length.
+pub fn sample_size_(rng: &mut Rng, len: usize, n: usize) -> Vec<usize> {
+    let n = n.min(len);
+    let mut indices: Vec<usize> = (0..len).collect();
+    // Partial Fisher–Yates: only the first `n` slots need to be settled.
+    for i in 0..n {
+        let j = i + rng.below((len - i) as u64) as usize;
+        indices.swap(i, j);
+    }
+    indices.truncate(n);
+    indices
+}
+
+/// Index chosen with probability proportional to its weight.
+///
+/// Weights must be finite and non-negative with a positive total; an empty list gives `None`.
+pub fn weighted_choice_(rng: &mut Rng, weights: &[f64]) -> Result<Option<usize>, NumbersError> {
+    if weights.is_empty() {
+        return Ok(None);
+    }
+    if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+        return Err(NumbersError::InvalidArgument(
+            "weights must be finite and non-negative".into(),
+        ));
+    }
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return Err(NumbersError::InvalidArgument(
+            "weights must not all be zero".into(),
+        ));
+    }
+    let target = rng.next_f64() * total;
+    let mut seen = 0.0;
+    for (i, w) in weights.iter().enumerate() {
+        seen += w;
+        if target < seen {
+            return Ok(Some(i));
+        }
+    }
+    // Rounding can leave `target` just past the running total; fall back to the last
+    // element that can actually be drawn.
+    Ok(weights.iter().rposition(|w| *w > 0.0))
+}
+
+/// Uniform sample of `k` items from a stream of unknown length (Algorithm R).
+#[derive(Debug, Clone)]
+pub struct Reservoir<T> {
+    k: usize,
+    seen: u64,
+    items: Vec<T>,
+}
+
+impl<T> Reservoir<T> {
+    pub fn new(k: usize) -> Self {
+        Reservoir {
+            k,
+            seen: 0,
+            items: Vec::with_capacity(k),
+        }
+    }
+
+    pub fn push(&mut self, rng: &mut Rng, item: T) {
+        self.seen += 1;
+        if self.items.len() < self.k {
+            self.items.push(item);
+            return;
+        }
+        let j = rng.below(self.seen) as usize;
+        if j < self.k {
+            self.items[j] = item;
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+}
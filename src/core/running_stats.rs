@@ -0,0 +1,307 @@
+use crate::core::numbers::NumbersError;
+
+// ─── t-digest ────────────────────────────────────────────────────────────────
+
+/// Largest accepted t-digest compression. The digest preallocates about this many
+/// centroids, so larger values only waste memory.
+pub const MAX_COMPRESSION: f64 = 1e6;
+
+/// Check that `compression` is a number in `[1, MAX_COMPRESSION]`.
+pub fn check_compression(compression: f64) -> Result<(), NumbersError> {
+    if !(1.0..=MAX_COMPRESSION).contains(&compression) {
+        return Err(NumbersError::InvalidArgument(format!(
+            "compression must be between 1 and {MAX_COMPRESSION}, got {compression}"
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Merging t-digest for approximate quantiles in bounded memory.
+///
+/// Points are buffered and periodically folded into at most ~`compression` centroids,
+/// which are kept small near the tails so extreme quantiles stay accurate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    buffer: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    pub fn new(compression: f64) -> Self {
+        TDigest {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn compression(&self) -> f64 {
+        self.compression
+    }
+
+    pub fn push(&mut self, x: f64) {
+        self.push_weighted(x, 1.0);
+    }
+
+    fn push_weighted(&mut self, mean: f64, weight: f64) {
+        self.min = self.min.min(mean);
+        self.max = self.max.max(mean);
+        self.buffer.push(Centroid { mean, weight });
+        if self.buffer.len() as f64 >= 5.0 * self.compression {
+            self.flush();
+        }
+    }
+
+    /// Fold every centroid of `other` into this digest.
+    pub fn merge(&mut self, other: &TDigest) {
+        for c in other.centroids.iter().chain(&other.buffer) {
+            self.push_weighted(c.mean, c.weight);
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.flush();
+    }
+
+    /// Scale function k1: maps a quantile to an index that grows fastest near the tails.
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * std::f64::consts::PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn k_inverse(&self, k: f64) -> f64 {
+        let angle = k * 2.0 * std::f64::consts::PI / self.compression;
+        if angle >= std::f64::consts::FRAC_PI_2 {
+            return 1.0;
+        }
+        (angle.sin() + 1.0) / 2.0
+    }
+
+    fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut points = std::mem::take(&mut self.centroids);
+        points.append(&mut self.buffer);
+        points.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = points.iter().map(|c| c.weight).sum();
+        let mut merged = Vec::with_capacity(self.compression as usize);
+        let mut seen = 0.0;
+        let mut limit = total * self.k_inverse(self.k(0.0) + 1.0);
+        let mut current = points[0];
+        for &point in &points[1..] {
+            if seen + current.weight + point.weight <= limit {
+                let weight = current.weight + point.weight;
+                current.mean += (point.mean - current.mean) * point.weight / weight;
+                current.weight = weight;
+            } else {
+                seen += current.weight;
+                limit = total * self.k_inverse(self.k(seen / total) + 1.0);
+                merged.push(current);
+                current = point;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+
+    /// Approximate `q`-quantile (`0 <= q <= 1`), or `None` when no value was pushed.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.flush();
+        let first = self.centroids.first()?;
+        let last = self.centroids.last()?;
+        if self.centroids.len() == 1 {
+            return Some(first.mean);
+        }
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let target = q * total;
+
+        // Between the minimum and the first centroid's center.
+        if target < first.weight / 2.0 {
+            let t = target / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * t);
+        }
+        // Between the last centroid's center and the maximum.
+        if target > total - last.weight / 2.0 {
+            let t = (total - target) / (last.weight / 2.0);
+            return Some(self.max - (self.max - last.mean) * t);
+        }
+
+        let mut center = first.weight / 2.0;
+        for pair in self.centroids.windows(2) {
+            let next_center = center + (pair[0].weight + pair[1].weight) / 2.0;
+            if target <= next_center {
+                let t = (target - center) / (next_center - center);
+                return Some(pair[0].mean + (pair[1].mean - pair[0].mean) * t);
+            }
+            center = next_center;
+        }
+        Some(last.mean)
+    }
+}
+
+// ─── Welford accumulator ─────────────────────────────────────────────────────
+
+/// Streaming statistics: Welford's algorithm for mean and variance, plus extremes
+/// and a t-digest for quantiles. NaN values are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+    digest: TDigest,
+}
+
+impl Default for RunningStats {
+    fn default() -> Self {
+        RunningStats::new(100.0)
+    }
+}
+
+impl RunningStats {
+    /// `compression` bounds the number of t-digest centroids; higher is more accurate.
+    pub fn new(compression: f64) -> Self {
+        RunningStats {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            digest: TDigest::new(compression),
+        }
+    }
+
+    pub fn push(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+        self.digest.push(x);
+    }
+
+    pub fn extend(&mut self, values: &[f64]) {
+        for &x in values {
+            self.push(x);
+        }
+    }
+
+    /// Combine another accumulator into this one (Chan et al. parallel update).
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 +=
+            other.m2 + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.digest.merge(&other.digest);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Sample variance (`ddof = 1`), or `None` with fewer than two values.
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    pub fn std(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Approximate `q`-quantile from the t-digest.
+    pub fn quantile(&mut self, q: f64) -> Result<Option<f64>, NumbersError> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(NumbersError::InvalidArgument(
+                "quantile must be between 0 and 1".into(),
+            ));
+        }
+        Ok(self.digest.quantile(q))
+    }
+
+    /// Serialize to little-endian bytes, so partial aggregates can cross process boundaries.
+    pub fn to_bytes(&mut self) -> Vec<u8> {
+        self.digest.flush();
+        let header = [
+            self.count as f64,
+            self.mean,
+            self.m2,
+            self.min,
+            self.max,
+            self.digest.compression,
+        ];
+        let centroids = self
+            .digest
+            .centroids
+            .iter()
+            .flat_map(|c| [c.mean, c.weight]);
+        header
+            .into_iter()
+            .chain(centroids)
+            .flat_map(f64::to_le_bytes)
+            .collect()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NumbersError> {
+        let invalid = || NumbersError::InvalidArgument("invalid RunningStats state".into());
+        // Six header values followed by (mean, weight) pairs, eight bytes each.
+        if bytes.len() < 6 * 8 || !(bytes.len() - 6 * 8).is_multiple_of(2 * 8) {
+            return Err(invalid());
+        }
+        let values: Vec<f64> = bytes
+            .chunks_exact(8)
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        check_compression(values[5])?;
+        let mut stats = RunningStats::new(values[5]);
+        stats.count = values[0] as u64;
+        stats.mean = values[1];
+        stats.m2 = values[2];
+        stats.min = values[3];
+        stats.max = values[4];
+        stats.digest.min = values[3];
+        stats.digest.max = values[4];
+        stats.digest.centroids = values[6..]
+            .chunks_exact(2)
+            .map(|c| Centroid {
+                mean: c[0],
+                weight: c[1],
+            })
+            .collect();
+        Ok(stats)
+    }
+}
@@ -0,0 +1,304 @@
+use rustdash::core::arrays::{
+    chunks_, difference_, intersection_, join_, order_by_, split_index_, try_sort_by, union_,
+    unique_, windows_, xor_, AggFunc, AggNumber, GroupValues, JoinHow, Keep, NullsPosition,
+    SortOrder,
+};
+
+// ==================== intersection Tests ====================
+#[test]
+fn test_intersection_variadic() {
+    let lists = vec![vec![1, 2, 3, 2], vec![2, 3, 4], vec![3, 2, 5]];
+    assert_eq!(intersection_(&lists), vec![(0, 1), (0, 2)]);
+}
+
+#[test]
+fn test_intersection_single_and_empty() {
+    assert_eq!(intersection_(&[vec![1, 1, 2]]), vec![(0, 0), (0, 2)]);
+    assert!(intersection_::<i32>(&[]).is_empty());
+}
+
+// ==================== difference Tests ====================
+#[test]
+fn test_difference_keeps_duplicates() {
+    let lists = vec![vec!["a", "b", "a", "c"], vec!["b"], vec!["c"]];
+    assert_eq!(difference_(&lists), vec![(0, 0), (0, 2)]);
+}
+
+#[test]
+fn test_difference_empty() {
+    assert!(difference_::<i32>(&[]).is_empty());
+    assert_eq!(difference_(&[vec![1], vec![]]), vec![(0, 0)]);
+}
+
+// ==================== union Tests ====================
+#[test]
+fn test_union_first_appearance_order() {
+    let lists = vec![vec![2, 1, 2], vec![3, 1], vec![4, 3]];
+    assert_eq!(union_(&lists), vec![(0, 0), (0, 1), (1, 0), (2, 0)]);
+}
+
+// ==================== xor Tests ====================
+#[test]
+fn test_xor_exactly_one_list() {
+    let lists = vec![vec![1, 2, 2, 5], vec![2, 3], vec![3, 4]];
+    assert_eq!(xor_(&lists), vec![(0, 0), (0, 3), (2, 1)]);
+}
+
+#[test]
+fn test_xor_duplicates_within_one_list() {
+    assert_eq!(xor_(&[vec![1, 1], vec![2]]), vec![(0, 0), (1, 0)]);
+}
+
+// ==================== unique Tests ====================
+#[test]
+fn test_unique_keep_first() {
+    assert_eq!(unique_(&[3, 1, 3, 2, 1], Keep::First), vec![0, 1, 3]);
+}
+
+#[test]
+fn test_unique_keep_last() {
+    assert_eq!(unique_(&[3, 1, 3, 2, 1], Keep::Last), vec![2, 3, 4]);
+}
+
+#[test]
+fn test_keep_from_str() {
+    assert_eq!("last".parse::<Keep>(), Ok(Keep::Last));
+    assert!("middle".parse::<Keep>().is_err());
+}
+
+// ==================== order_by Tests ====================
+fn compare_ok(a: &i32, b: &i32) -> Result<std::cmp::Ordering, ()> {
+    Ok(a.cmp(b))
+}
+
+#[test]
+fn test_order_by_multiple_keys() {
+    let columns = vec![
+        vec![Some(1), Some(2), Some(1), Some(2)],
+        vec![Some(9), Some(8), Some(7), Some(6)],
+    ];
+    let order = order_by_(
+        4,
+        &columns,
+        &[SortOrder::Asc, SortOrder::Desc],
+        NullsPosition::Last,
+        compare_ok,
+    );
+    assert_eq!(order, Ok(vec![0, 2, 1, 3]));
+}
+
+#[test]
+fn test_order_by_is_stable() {
+    let columns = vec![vec![Some(1), Some(0), Some(1), Some(0), Some(1)]];
+    let order = order_by_(5, &columns, &[], NullsPosition::Last, compare_ok);
+    assert_eq!(order, Ok(vec![1, 3, 0, 2, 4]));
+    let order = order_by_(
+        5,
+        &columns,
+        &[SortOrder::Desc],
+        NullsPosition::Last,
+        compare_ok,
+    );
+    assert_eq!(order, Ok(vec![0, 2, 4, 1, 3]));
+}
+
+#[test]
+fn test_order_by_nulls_ignore_direction() {
+    let columns = vec![vec![None, Some(2), Some(1), None]];
+    let first = order_by_(
+        4,
+        &columns,
+        &[SortOrder::Desc],
+        NullsPosition::First,
+        compare_ok,
+    );
+    assert_eq!(first, Ok(vec![0, 3, 1, 2]));
+    let last = order_by_(
+        4,
+        &columns,
+        &[SortOrder::Desc],
+        NullsPosition::Last,
+        compare_ok,
+    );
+    assert_eq!(last, Ok(vec![1, 2, 0, 3]));
+}
+
+#[test]
+fn test_order_by_propagates_errors() {
+    let columns = vec![vec![Some(1), Some(-1), Some(2)]];
+    let order = order_by_(3, &columns, &[], NullsPosition::Last, |a: &i32, b: &i32| {
+        if *a < 0 || *b < 0 {
+            Err("negative")
+        } else {
+            Ok(a.cmp(b))
+        }
+    });
+    assert_eq!(order, Err("negative"));
+}
+
+#[test]
+fn test_order_by_without_keys_keeps_rows() {
+    let columns: Vec<Vec<Option<i32>>> = vec![];
+    let order = order_by_(3, &columns, &[], NullsPosition::Last, compare_ok);
+    assert_eq!(order, Ok(vec![0, 1, 2]));
+}
+
+#[test]
+fn test_try_sort_by_matches_std_sort() {
+    let mut items: Vec<u64> = (0..200).map(|i| (i * 7919) % 101).collect();
+    let mut expected = items.clone();
+    expected.sort();
+    try_sort_by(&mut items, &mut |a: &u64, b: &u64| Ok::<_, ()>(a.cmp(b))).unwrap();
+    assert_eq!(items, expected);
+}
+
+#[test]
+fn test_sort_order_from_str() {
+    assert_eq!("desc".parse::<SortOrder>(), Ok(SortOrder::Desc));
+    assert!("down".parse::<SortOrder>().is_err());
+    assert_eq!("first".parse::<NullsPosition>(), Ok(NullsPosition::First));
+    assert!("top".parse::<NullsPosition>().is_err());
+}
+
+// ==================== aggregate Tests ====================
+#[test]
+fn test_group_values_int_aggregates_stay_ints() {
+    let mut values = GroupValues::default();
+    for i in [3, 1, 2] {
+        values.push_int(i);
+    }
+    assert_eq!(values.aggregate(AggFunc::Sum), Some(AggNumber::Int(6)));
+    assert_eq!(values.aggregate(AggFunc::Min), Some(AggNumber::Int(1)));
+    assert_eq!(values.aggregate(AggFunc::Max), Some(AggNumber::Int(3)));
+    assert_eq!(values.aggregate(AggFunc::Mean), Some(AggNumber::Float(2.0)));
+    assert_eq!(
+        values.aggregate(AggFunc::Median),
+        Some(AggNumber::Float(2.0))
+    );
+    assert_eq!(values.aggregate(AggFunc::Var), Some(AggNumber::Float(1.0)));
+}
+
+#[test]
+fn test_group_values_switch_to_floats() {
+    let mut values = GroupValues::default();
+    values.push_int(1);
+    values.push_float(0.5);
+    values.push_int(2);
+    assert_eq!(values, GroupValues::Floats(vec![1.0, 0.5, 2.0]));
+    assert_eq!(values.aggregate(AggFunc::Sum), Some(AggNumber::Float(3.5)));
+}
+
+#[test]
+fn test_group_values_sum_past_i64_stays_exact() {
+    let mut values = GroupValues::default();
+    values.push_int(i64::MAX as i128);
+    values.push_int(1);
+    assert_eq!(
+        values.aggregate(AggFunc::Sum),
+        Some(AggNumber::Int(i64::MAX as i128 + 1))
+    );
+}
+
+#[test]
+fn test_group_values_sum_overflow_falls_back_to_float() {
+    let mut values = GroupValues::default();
+    values.push_int(i128::MAX);
+    values.push_int(1);
+    assert_eq!(
+        values.aggregate(AggFunc::Sum),
+        Some(AggNumber::Float(i128::MAX as f64 + 1.0))
+    );
+}
+
+#[test]
+fn test_group_values_empty() {
+    let values = GroupValues::default();
+    assert_eq!(values.aggregate(AggFunc::Sum), Some(AggNumber::Int(0)));
+    assert_eq!(values.aggregate(AggFunc::Mean), None);
+    assert_eq!(values.aggregate(AggFunc::Std), None);
+}
+
+#[test]
+fn test_agg_func_from_str() {
+    assert_eq!("median".parse::<AggFunc>(), Ok(AggFunc::Median));
+    assert!("total".parse::<AggFunc>().is_err());
+    assert!(AggFunc::Std.is_numeric());
+    assert!(!AggFunc::Count.is_numeric());
+}
+
+// ==================== join Tests ====================
+#[test]
+fn test_join_inner_one_to_many() {
+    let left = [Some(1), Some(2), Some(3)];
+    let right = [Some(2), Some(1), Some(2)];
+    assert_eq!(
+        join_(&left, &right, JoinHow::Inner),
+        vec![(Some(0), Some(1)), (Some(1), Some(0)), (Some(1), Some(2))]
+    );
+}
+
+#[test]
+fn test_join_null_keys_never_match() {
+    let left = [None, Some(1)];
+    let right = [None, Some(1)];
+    assert_eq!(
+        join_(&left, &right, JoinHow::Inner),
+        vec![(Some(1), Some(1))]
+    );
+    assert_eq!(
+        join_(&left, &right, JoinHow::Outer),
+        vec![(Some(0), None), (Some(1), Some(1)), (None, Some(0))]
+    );
+}
+
+#[test]
+fn test_join_left_and_right() {
+    let left = [Some(1), Some(2)];
+    let right = [Some(3), Some(1)];
+    assert_eq!(
+        join_(&left, &right, JoinHow::Left),
+        vec![(Some(0), Some(1)), (Some(1), None)]
+    );
+    assert_eq!(
+        join_(&left, &right, JoinHow::Right),
+        vec![(None, Some(0)), (Some(0), Some(1))]
+    );
+}
+
+#[test]
+fn test_join_how_from_str() {
+    assert_eq!("outer".parse::<JoinHow>(), Ok(JoinHow::Outer));
+    assert!("cross".parse::<JoinHow>().is_err());
+}
+
+// ==================== windows Tests ====================
+#[test]
+fn test_windows_full_and_partial() {
+    assert_eq!(windows_(4, 2, 1, false), Ok(vec![0..2, 1..3, 2..4]));
+    assert_eq!(windows_(5, 3, 2, true), Ok(vec![0..3, 2..5, 4..5]));
+    assert_eq!(windows_(2, 3, 1, false), Ok(vec![]));
+}
+
+#[test]
+fn test_windows_rejects_zero_size_or_step() {
+    assert!(windows_(3, 0, 1, false).is_err());
+    assert!(windows_(3, 1, 0, false).is_err());
+}
+
+// ==================== chunks Tests ====================
+#[test]
+fn test_chunks() {
+    assert_eq!(chunks_(5, 2, false), Ok(vec![0..2, 2..4, 4..5]));
+    assert_eq!(chunks_(0, 2, false), Ok(vec![]));
+    assert!(chunks_(5, 2, true).is_err());
+    assert!(chunks_(5, 0, false).is_err());
+}
+
+// ==================== split_index Tests ====================
+#[test]
+fn test_split_index() {
+    assert_eq!(split_index_(4, 1), 1);
+    assert_eq!(split_index_(4, -1), 3);
+    assert_eq!(split_index_(4, 9), 4);
+    assert_eq!(split_index_(4, -9), 0);
+}
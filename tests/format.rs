@@ -0,0 +1,105 @@
+use rustdash::core::format::{
+    format_bytes_, format_compact_, format_duration_, format_integer_, format_number_,
+    format_percent_, parse_bytes_, parse_number_, NumberLocale,
+};
+
+// ==================== format_number_ Tests ====================
+#[test]
+fn test_format_number_grouping() {
+    assert_eq!(format_number_(1234567.891, 2, ",", "."), "1,234,567.89");
+    assert_eq!(format_number_(123.0, 0, ",", "."), "123");
+    assert_eq!(format_number_(-1234.5, 1, ".", ","), "-1.234,5");
+    assert_eq!(format_number_(0.5, 0, ",", "."), "1");
+    assert_eq!(format_number_(-0.001, 2, ",", "."), "0.00");
+    assert_eq!(format_number_(f64::NAN, 2, ",", "."), "NaN");
+}
+
+#[test]
+fn test_format_integer_exact() {
+    assert_eq!(
+        format_integer_(i64::MIN, 0, ",", "."),
+        "-9,223,372,036,854,775,808"
+    );
+    assert_eq!(format_integer_(1000, 2, " ", ","), "1 000,00");
+}
+
+// ==================== unit formatter Tests ====================
+#[test]
+fn test_format_bytes() {
+    assert_eq!(format_bytes_(1572864.0, true, 1), "1.5 MiB");
+    assert_eq!(format_bytes_(1572864.0, false, 1), "1.6 MB");
+    assert_eq!(format_bytes_(1023.0, true, 1), "1023 B");
+    assert_eq!(format_bytes_(1048575.0, true, 1), "1 MiB");
+}
+
+#[test]
+fn test_format_compact() {
+    assert_eq!(format_compact_(1234.0, 1), "1.2k");
+    assert_eq!(format_compact_(999_950.0, 1), "1M");
+    assert_eq!(format_compact_(-1500.0, 1), "-1.5k");
+    assert_eq!(format_compact_(12.0, 1), "12");
+}
+
+#[test]
+fn test_format_percent() {
+    assert_eq!(format_percent_(0.125, 1), "12.5%");
+    assert_eq!(format_percent_(1.0, 0), "100%");
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration_(3725.0), "1h 2m 5s");
+    assert_eq!(format_duration_(1.5), "1.5s");
+    assert_eq!(format_duration_(0.25), "250ms");
+    assert_eq!(format_duration_(0.9996), "1s");
+    assert_eq!(format_duration_(59.97), "1m");
+    assert_eq!(format_duration_(0.0), "0s");
+}
+
+// ==================== parsing Tests ====================
+#[test]
+fn test_number_locale_from_str() {
+    assert_eq!("en".parse::<NumberLocale>().unwrap(), NumberLocale::Point);
+    assert_eq!(
+        "de_DE".parse::<NumberLocale>().unwrap(),
+        NumberLocale::Comma
+    );
+    assert_eq!(
+        "fr-CA".parse::<NumberLocale>().unwrap(),
+        NumberLocale::Space
+    );
+    assert_eq!(
+        "de-CH".parse::<NumberLocale>().unwrap(),
+        NumberLocale::Apostrophe
+    );
+    assert!("xx".parse::<NumberLocale>().is_err());
+}
+
+#[test]
+fn test_parse_number() {
+    assert_eq!(
+        parse_number_("1,234.56", NumberLocale::Point).unwrap(),
+        1234.56
+    );
+    assert_eq!(
+        parse_number_(" 1.234,56 ", NumberLocale::Comma).unwrap(),
+        1234.56
+    );
+    assert_eq!(
+        parse_number_("1\u{a0}234,5", NumberLocale::Space).unwrap(),
+        1234.5
+    );
+    assert!(parse_number_("NaN", NumberLocale::Point).is_err());
+    assert!(parse_number_("", NumberLocale::Point).is_err());
+}
+
+#[test]
+fn test_parse_bytes() {
+    assert_eq!(parse_bytes_("10MB").unwrap(), 10_000_000);
+    assert_eq!(parse_bytes_("1.5 GiB").unwrap(), 1_610_612_736);
+    assert_eq!(parse_bytes_("1 kib").unwrap(), 1024);
+    assert_eq!(parse_bytes_("512 B").unwrap(), 512);
+    assert!(parse_bytes_("MB").is_err());
+    assert!(parse_bytes_("10 XB").is_err());
+    assert!(parse_bytes_("100 EB").is_err());
+}
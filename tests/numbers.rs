@@ -1,4 +1,12 @@
-use rustdash::core::numbers::{max_, mean_, min_, round_, sum_};
+use rustdash::core::numbers::{
+    apply_nan_policy_, argmax_, argmin_, clamp_, correlation_, covariance_, cummax_, cummin_,
+    cumprod_, cumsum_, diff_, digitize_, ewm_mean_, factorial_, fill_gaps_, gcd_, geometric_mean_,
+    harmonic_mean_, histogram_, in_range_, interp_, is_prime_, lcm_, lerp_, linear_regression_,
+    linspace_, max_, mean_, median_, min_, min_max_, mod_pow_, n_choose_k_, normalize_,
+    pct_change_, primes_up_to_, range_, range_right_, resample_, rescale_, robust_scale_, rolling_,
+    round_, standardize_, sum_, times_, top_k_, variance_, weighted_mean_, CorrelationMethod,
+    ExactNumber, FillMethod, HistogramBins, NanPolicy, NumbersError, ResampleAgg, RollingStat,
+};
 
 // ==================== sum_ Tests ====================
 #[test]
@@ -112,36 +120,51 @@ fn test_min_floats() {
 
 #[test]
 fn test_mean_basic() {
-    assert!((mean_(&[1, 2, 3]) - 2.0).abs() < f64::EPSILON);
-    assert!((mean_(&[10, 20, 30]) - 20.0).abs() < f64::EPSILON);
+    assert!((mean_(&[1, 2, 3]).unwrap() - 2.0).abs() < f64::EPSILON);
+    assert!((mean_(&[10, 20, 30]).unwrap() - 20.0).abs() < f64::EPSILON);
 }
 
 #[test]
 fn test_mean_single_element() {
-    assert!((mean_(&[5]) - 5.0).abs() < f64::EPSILON);
+    assert!((mean_(&[5]).unwrap() - 5.0).abs() < f64::EPSILON);
 }
 
 #[test]
 fn test_mean_empty() {
     let empty: &[i32] = &[];
-    assert!(mean_(empty).is_nan());
+    assert_eq!(mean_(empty), None);
 }
 
 #[test]
 fn test_mean_negative_numbers() {
-    assert!((mean_(&[-1, -2, -3]) - (-2.0)).abs() < f64::EPSILON);
-    assert!((mean_(&[-2, 0, 2]) - 0.0).abs() < f64::EPSILON);
+    assert!((mean_(&[-1, -2, -3]).unwrap() - (-2.0)).abs() < f64::EPSILON);
+    assert!((mean_(&[-2, 0, 2]).unwrap() - 0.0).abs() < f64::EPSILON);
 }
 
 #[test]
 fn test_mean_floats() {
-    assert!((mean_(&[1.0, 2.0, 3.0]) - 2.0).abs() < f64::EPSILON);
-    assert!((mean_(&[2.5, 3.5, 4.0]) - (10.0 / 3.0)).abs() < 1e-10);
+    assert!((mean_(&[1.0, 2.0, 3.0]).unwrap() - 2.0).abs() < f64::EPSILON);
+    assert!((mean_(&[2.5, 3.5, 4.0]).unwrap() - (10.0 / 3.0)).abs() < 1e-10);
 }
 
 #[test]
 fn test_mean_large_numbers() {
-    assert!((mean_(&[1000000, 2000000, 3000000]) - 2000000.0).abs() < f64::EPSILON);
+    assert!((mean_(&[1000000, 2000000, 3000000]).unwrap() - 2000000.0).abs() < f64::EPSILON);
+}
+
+// ==================== median_ / variance_ Tests ====================
+#[test]
+fn test_median_odd_and_even() {
+    assert_eq!(median_(&[3.0, 1.0, 2.0]), Some(2.0));
+    assert_eq!(median_(&[4.0, 1.0, 3.0, 2.0]), Some(2.5));
+    assert_eq!(median_(&[]), None);
+    assert!(median_(&[1.0, f64::NAN]).unwrap().is_nan());
+}
+
+#[test]
+fn test_variance_sample() {
+    assert_eq!(variance_(&[1.0, 2.0, 3.0, 4.0]), Some(5.0 / 3.0));
+    assert_eq!(variance_(&[1.0]), None);
 }
 
 // ==================== round_ Tests (single value) ====================
@@ -222,3 +245,894 @@ fn test_round_array_single_element() {
     assert_eq!(result.len(), 1);
     assert!((result[0] - 3.142).abs() < f64::EPSILON);
 }
+
+// ==================== cumulative Tests ====================
+
+#[test]
+fn test_cumsum_basic() {
+    assert_eq!(
+        cumsum_(&[1, 2, 3, 4], NanPolicy::Propagate),
+        Ok(vec![1, 3, 6, 10])
+    );
+    assert_eq!(cumsum_(&[] as &[i64], NanPolicy::Propagate), Ok(vec![]));
+}
+
+#[test]
+fn test_cumprod_basic() {
+    assert_eq!(
+        cumprod_(&[1, 2, 3, 4], NanPolicy::Propagate),
+        Ok(vec![1, 2, 6, 24])
+    );
+}
+
+#[test]
+fn test_cummax_cummin() {
+    assert_eq!(
+        cummax_(&[1, 3, 2, 5, 4], NanPolicy::Propagate),
+        Ok(vec![1, 3, 3, 5, 5])
+    );
+    assert_eq!(
+        cummin_(&[5, 3, 4, 1, 2], NanPolicy::Propagate),
+        Ok(vec![5, 3, 3, 1, 1])
+    );
+}
+
+#[test]
+fn test_cumsum_nan_propagate() {
+    let result = cumsum_(&[1.0, f64::NAN, 2.0], NanPolicy::Propagate).unwrap();
+    assert_eq!(result[0], 1.0);
+    assert!(result[1].is_nan());
+    assert!(result[2].is_nan());
+}
+
+#[test]
+fn test_cummax_nan_omit() {
+    let result = cummax_(&[1.0, f64::NAN, 3.0, 2.0], NanPolicy::Omit).unwrap();
+    assert_eq!(result[0], 1.0);
+    assert!(result[1].is_nan());
+    assert_eq!(&result[2..], &[3.0, 3.0]);
+}
+
+#[test]
+fn test_cumsum_nan_raise() {
+    assert_eq!(
+        cumsum_(&[1.0, f64::NAN], NanPolicy::Raise),
+        Err(NumbersError::NanFound)
+    );
+}
+
+#[test]
+fn test_diff_basic() {
+    assert_eq!(
+        diff_(&[1, 4, 9, 16], 1, NanPolicy::Propagate),
+        Ok(vec![3, 5, 7])
+    );
+    assert_eq!(
+        diff_(&[1, 4, 9, 16], 2, NanPolicy::Propagate),
+        Ok(vec![8, 12])
+    );
+    assert_eq!(diff_(&[1, 2], 5, NanPolicy::Propagate), Ok(vec![]));
+}
+
+#[test]
+fn test_cumulative_int_overflow() {
+    let big = 1 << 62;
+    assert_eq!(
+        cumsum_(&[big, big], NanPolicy::Propagate),
+        Err(NumbersError::Overflow)
+    );
+    assert_eq!(
+        cumprod_(&[10; 20], NanPolicy::Propagate),
+        Err(NumbersError::Overflow)
+    );
+    assert_eq!(
+        diff_(&[i64::MIN, big], 1, NanPolicy::Propagate),
+        Err(NumbersError::Overflow)
+    );
+}
+
+#[test]
+fn test_diff_zero_lag() {
+    assert!(diff_(&[1, 2], 0, NanPolicy::Propagate).is_err());
+}
+
+#[test]
+fn test_pct_change_basic() {
+    let result = pct_change_(&[100.0, 110.0, 99.0], 1, NanPolicy::Propagate).unwrap();
+    assert!((result[0] - 0.1).abs() < 1e-10);
+    assert!((result[1] - (-0.1)).abs() < 1e-10);
+}
+
+#[test]
+fn test_diff_and_pct_change_omit_drop_nan() {
+    let nan = f64::NAN;
+    assert_eq!(
+        diff_(&[1.0, nan, 4.0, 9.0], 1, NanPolicy::Omit),
+        Ok(vec![3.0, 5.0])
+    );
+    assert_eq!(
+        pct_change_(&[1.0, nan, 2.0], 1, NanPolicy::Omit),
+        Ok(vec![1.0])
+    );
+    assert_eq!(
+        diff_(&[1.0, nan], 1, NanPolicy::Raise),
+        Err(NumbersError::NanFound)
+    );
+}
+
+#[test]
+fn test_pct_change_from_zero() {
+    let result = pct_change_(&[0.0, 1.0, -1.0], 1, NanPolicy::Propagate).unwrap();
+    assert_eq!(result[0], f64::INFINITY);
+    let result = pct_change_(&[0.0, 0.0], 1, NanPolicy::Propagate).unwrap();
+    assert!(result[0].is_nan());
+}
+
+#[test]
+fn test_ewm_mean_basic() {
+    let result = ewm_mean_(&[1.0, 2.0, 3.0], 0.5, NanPolicy::Propagate).unwrap();
+    assert_eq!(result, vec![1.0, 1.5, 2.25]);
+}
+
+#[test]
+fn test_ewm_mean_invalid_alpha() {
+    assert!(ewm_mean_(&[1.0], 0.0, NanPolicy::Propagate).is_err());
+    assert!(ewm_mean_(&[1.0], 1.5, NanPolicy::Propagate).is_err());
+}
+
+// ==================== rolling_ Tests ====================
+
+#[test]
+fn test_rolling_mean_and_sum() {
+    let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+    assert_eq!(
+        rolling_(&values, 3, RollingStat::Mean, NanPolicy::Propagate),
+        Ok(vec![2.0, 3.0, 4.0])
+    );
+    assert_eq!(
+        rolling_(&values, 2, RollingStat::Sum, NanPolicy::Propagate),
+        Ok(vec![3.0, 5.0, 7.0, 9.0])
+    );
+}
+
+#[test]
+fn test_rolling_min_max() {
+    let values = [4.0, 2.0, 12.0, 3.0, 1.0, 7.0];
+    assert_eq!(
+        rolling_(&values, 3, RollingStat::Min, NanPolicy::Propagate),
+        Ok(vec![2.0, 2.0, 1.0, 1.0])
+    );
+    assert_eq!(
+        rolling_(&values, 3, RollingStat::Max, NanPolicy::Propagate),
+        Ok(vec![12.0, 12.0, 12.0, 7.0])
+    );
+}
+
+#[test]
+fn test_rolling_std() {
+    let result = rolling_(
+        &[2.0, 4.0, 4.0, 4.0, 5.0],
+        3,
+        RollingStat::Std,
+        NanPolicy::Propagate,
+    )
+    .unwrap();
+    assert!((result[0] - 1.1547005383792515).abs() < 1e-10);
+    assert!(result[1].abs() < 1e-10);
+}
+
+#[test]
+fn test_rolling_window_too_large() {
+    assert_eq!(
+        rolling_(&[1.0, 2.0], 3, RollingStat::Mean, NanPolicy::Propagate),
+        Ok(vec![])
+    );
+}
+
+#[test]
+fn test_rolling_zero_window() {
+    assert!(rolling_(&[1.0], 0, RollingStat::Mean, NanPolicy::Propagate).is_err());
+}
+
+#[test]
+fn test_rolling_nan_policies() {
+    let values = [1.0, f64::NAN, 3.0, 5.0];
+    let propagated = rolling_(&values, 2, RollingStat::Max, NanPolicy::Propagate).unwrap();
+    assert!(propagated[0].is_nan() && propagated[1].is_nan());
+    assert_eq!(propagated[2], 5.0);
+
+    let omitted = rolling_(&values, 2, RollingStat::Mean, NanPolicy::Omit).unwrap();
+    assert_eq!(omitted, vec![1.0, 3.0, 4.0]);
+
+    assert_eq!(
+        rolling_(&values, 2, RollingStat::Sum, NanPolicy::Raise),
+        Err(NumbersError::NanFound)
+    );
+}
+
+#[test]
+fn test_rolling_infinity_only_affects_its_windows() {
+    let values = [f64::INFINITY, 1.0, 2.0, 3.0, 4.0];
+    assert_eq!(
+        rolling_(&values, 2, RollingStat::Sum, NanPolicy::Propagate),
+        Ok(vec![f64::INFINITY, 3.0, 5.0, 7.0])
+    );
+    let result = rolling_(
+        &[1.0, f64::NEG_INFINITY, 2.0],
+        2,
+        RollingStat::Std,
+        NanPolicy::Propagate,
+    )
+    .unwrap();
+    assert!(result.iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn test_rolling_no_drift_or_cancellation() {
+    assert_eq!(
+        rolling_(
+            &[1e16, 1.0, 2.0, 3.0],
+            2,
+            RollingStat::Sum,
+            NanPolicy::Propagate
+        ),
+        Ok(vec![1e16, 3.0, 5.0])
+    );
+    let values = [1e9 + 1.0, 1e9 + 2.0, 1e9 + 3.0, 1e9 + 4.0];
+    assert_eq!(
+        rolling_(&values, 3, RollingStat::Std, NanPolicy::Propagate),
+        Ok(vec![1.0, 1.0])
+    );
+}
+
+// ==================== NaN policy Tests ====================
+
+#[test]
+fn test_max_nan_is_position_independent() {
+    assert!(max_(&[f64::NAN, 1.0, 2.0]).unwrap().is_nan());
+    assert!(max_(&[1.0, f64::NAN, 2.0]).unwrap().is_nan());
+    assert!(max_(&[1.0, 2.0, f64::NAN]).unwrap().is_nan());
+}
+
+#[test]
+fn test_min_nan_is_position_independent() {
+    assert!(min_(&[f64::NAN, 1.0]).unwrap().is_nan());
+    assert!(min_(&[1.0, f64::NAN]).unwrap().is_nan());
+}
+
+#[test]
+fn test_apply_nan_policy_omit() {
+    let values = [1.0, f64::NAN, 3.0];
+    let kept = apply_nan_policy_(&values, NanPolicy::Omit).unwrap();
+    assert_eq!(&kept[..], &[1.0, 3.0]);
+    assert_eq!(max_(&kept), Some(3.0));
+    assert_eq!(mean_(&kept), Some(2.0));
+}
+
+#[test]
+fn test_apply_nan_policy_propagate() {
+    let values = [1.0, f64::NAN];
+    let kept = apply_nan_policy_(&values, NanPolicy::Propagate).unwrap();
+    assert_eq!(kept.len(), 2);
+    assert!(sum_(&kept).is_nan());
+}
+
+#[test]
+fn test_apply_nan_policy_raise() {
+    assert_eq!(
+        apply_nan_policy_(&[1.0, f64::NAN], NanPolicy::Raise),
+        Err(NumbersError::NanFound)
+    );
+    assert!(apply_nan_policy_(&[1, 2], NanPolicy::Raise).is_ok());
+}
+
+#[test]
+fn test_mean_all_nan_omitted_is_none() {
+    let kept = apply_nan_policy_(&[f64::NAN, f64::NAN], NanPolicy::Omit).unwrap();
+    assert_eq!(mean_(&kept), None);
+}
+
+// ==================== weighted / geometric / harmonic mean Tests ====================
+
+#[test]
+fn test_weighted_mean_basic() {
+    assert_eq!(
+        weighted_mean_(&[1.0, 2.0, 3.0], &[3.0, 1.0, 0.0]),
+        Ok(Some(1.25))
+    );
+    assert_eq!(weighted_mean_(&[10, 20], &[1, 1]), Ok(Some(15.0)));
+}
+
+#[test]
+fn test_weighted_mean_empty() {
+    assert_eq!(weighted_mean_::<f64, f64>(&[], &[]), Ok(None));
+}
+
+#[test]
+fn test_weighted_mean_errors() {
+    assert!(weighted_mean_(&[1.0, 2.0], &[1.0]).is_err());
+    assert!(weighted_mean_(&[1.0, 2.0], &[0.0, 0.0]).is_err());
+    assert!(weighted_mean_(&[1.0, 2.0], &[1.0, -1.0]).is_err());
+}
+
+#[test]
+fn test_geometric_mean_basic() {
+    let result = geometric_mean_(&[1.0, 4.0, 16.0]).unwrap().unwrap();
+    assert!((result - 4.0).abs() < 1e-10);
+    assert_eq!(geometric_mean_::<f64>(&[]), Ok(None));
+}
+
+#[test]
+fn test_geometric_mean_non_positive() {
+    assert!(geometric_mean_(&[1.0, 0.0]).is_err());
+    assert!(geometric_mean_(&[1.0, -2.0]).is_err());
+}
+
+#[test]
+fn test_harmonic_mean_basic() {
+    let result = harmonic_mean_(&[40.0, 60.0]).unwrap().unwrap();
+    assert!((result - 48.0).abs() < 1e-10);
+    assert_eq!(harmonic_mean_::<f64>(&[]), Ok(None));
+}
+
+#[test]
+fn test_harmonic_mean_non_positive() {
+    assert!(harmonic_mean_(&[2.0, 0.0]).is_err());
+}
+
+// ==================== covariance / correlation / regression Tests ====================
+
+#[test]
+fn test_covariance_basic() {
+    let result = covariance_(&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.0, 6.0, 8.0]).unwrap();
+    assert!((result - 10.0 / 3.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_covariance_errors() {
+    assert!(covariance_(&[1.0, 2.0], &[1.0]).is_err());
+    assert!(covariance_(&[1.0], &[1.0]).is_err());
+}
+
+#[test]
+fn test_correlation_pearson() {
+    let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let up = correlation_(&xs, &[2.0, 4.0, 6.0, 8.0, 10.0], CorrelationMethod::Pearson).unwrap();
+    let down = correlation_(&xs, &[5.0, 4.0, 3.0, 2.0, 1.0], CorrelationMethod::Pearson).unwrap();
+    assert!((up - 1.0).abs() < 1e-10);
+    assert!((down + 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_correlation_spearman_monotonic() {
+    let xs = [1.0, 2.0, 3.0, 4.0];
+    let ys = [1.0, 8.0, 27.0, 64.0];
+    let result = correlation_(&xs, &ys, CorrelationMethod::Spearman).unwrap();
+    assert!((result - 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_correlation_spearman_ties() {
+    let xs = [1.0, 2.0, 2.0, 3.0];
+    let ys = [1.0, 2.0, 3.0, 4.0];
+    let result = correlation_(&xs, &ys, CorrelationMethod::Spearman).unwrap();
+    assert!((result - 0.9486832980505138).abs() < 1e-10);
+}
+
+#[test]
+fn test_correlation_kendall() {
+    let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let ys = [3.0, 1.0, 2.0, 5.0, 4.0];
+    let result = correlation_(&xs, &ys, CorrelationMethod::Kendall).unwrap();
+    assert!((result - 0.4).abs() < 1e-10);
+}
+
+#[test]
+fn test_correlation_nan_propagates() {
+    let xs = [1.0, f64::NAN, 3.0, 4.0];
+    let ys = [1.0, 2.0, 3.0, 4.0];
+    for method in [
+        CorrelationMethod::Pearson,
+        CorrelationMethod::Spearman,
+        CorrelationMethod::Kendall,
+    ] {
+        assert!(correlation_(&xs, &ys, method).unwrap().is_nan());
+    }
+}
+
+#[test]
+fn test_correlation_constant_input() {
+    assert!(correlation_(
+        &[1.0, 1.0, 1.0],
+        &[1.0, 2.0, 3.0],
+        CorrelationMethod::Pearson
+    )
+    .is_err());
+    assert!(correlation_(
+        &[1.0, 1.0, 1.0],
+        &[1.0, 2.0, 3.0],
+        CorrelationMethod::Kendall
+    )
+    .is_err());
+}
+
+#[test]
+fn test_linear_regression_exact_fit() {
+    let fit = linear_regression_(&[0.0, 1.0, 2.0, 3.0], &[1.0, 3.0, 5.0, 7.0]).unwrap();
+    assert!((fit.slope - 2.0).abs() < 1e-10);
+    assert!((fit.intercept - 1.0).abs() < 1e-10);
+    assert!((fit.r_squared - 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_linear_regression_noisy() {
+    let fit = linear_regression_(&[1.0, 2.0, 3.0, 4.0], &[2.0, 3.0, 5.0, 4.0]).unwrap();
+    assert!((fit.slope - 0.8).abs() < 1e-10);
+    assert!((fit.intercept - 1.5).abs() < 1e-10);
+    assert!((fit.r_squared - 0.64).abs() < 1e-10);
+}
+
+#[test]
+fn test_linear_regression_constant_xs() {
+    assert!(linear_regression_(&[2.0, 2.0], &[1.0, 3.0]).is_err());
+}
+
+// ==================== histogram_ / digitize_ Tests ====================
+
+#[test]
+fn test_histogram_count() {
+    let result = histogram_(&[1.0, 2.0, 2.0, 3.0, 4.0], HistogramBins::Count(3), None).unwrap();
+    assert_eq!(result.counts, vec![1, 2, 2]);
+    assert_eq!(result.edges, vec![1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn test_histogram_edges() {
+    let bins = HistogramBins::Edges(vec![0.0, 10.0, 100.0]);
+    let result = histogram_(&[5.0, 10.0, 50.0, 100.0, 150.0, -1.0], bins, None).unwrap();
+    assert_eq!(result.counts, vec![1, 3]);
+}
+
+#[test]
+fn test_histogram_range() {
+    let result = histogram_(
+        &[0.0, 5.0, 10.0, 20.0],
+        HistogramBins::Count(2),
+        Some((0.0, 10.0)),
+    )
+    .unwrap();
+    assert_eq!(result.counts, vec![1, 2]);
+    assert_eq!(result.edges, vec![0.0, 5.0, 10.0]);
+}
+
+#[test]
+fn test_histogram_constant_values() {
+    let result = histogram_(&[3.0, 3.0], HistogramBins::Count(2), None).unwrap();
+    assert_eq!(result.edges, vec![2.5, 3.0, 3.5]);
+    assert_eq!(result.counts, vec![0, 2]);
+}
+
+#[test]
+fn test_histogram_ignores_nan() {
+    let result = histogram_(&[1.0, f64::NAN, 2.0], HistogramBins::Count(1), None).unwrap();
+    assert_eq!(result.counts, vec![2]);
+}
+
+#[test]
+fn test_histogram_ignores_infinity() {
+    let result = histogram_(&[0.0, f64::INFINITY, 1.0], HistogramBins::Count(2), None).unwrap();
+    assert_eq!(result.edges, vec![0.0, 0.5, 1.0]);
+    assert_eq!(result.counts, vec![1, 1]);
+    let auto = histogram_(&[1.0, f64::NEG_INFINITY], HistogramBins::Auto, None).unwrap();
+    assert_eq!(auto.counts, vec![1]);
+}
+
+#[test]
+fn test_histogram_bin_count_is_capped() {
+    let mut values = vec![0.0; 10];
+    values.extend((0..10).map(|i| 0.001 * i as f64));
+    values.push(1e9);
+    assert!(matches!(
+        histogram_(&values, HistogramBins::FreedmanDiaconis, None),
+        Err(NumbersError::InvalidArgument(_))
+    ));
+    let auto = histogram_(&values, HistogramBins::Auto, None).unwrap();
+    let sturges = histogram_(&values, HistogramBins::Sturges, None).unwrap();
+    assert_eq!(auto, sturges);
+    assert!(histogram_(&[1.0], HistogramBins::Count(usize::MAX), None).is_err());
+}
+
+#[test]
+fn test_histogram_methods() {
+    let values: Vec<f64> = (0..100).map(|x| x as f64).collect();
+    let sturges = histogram_(&values, HistogramBins::Sturges, None).unwrap();
+    assert_eq!(sturges.counts.len(), 8);
+    assert_eq!(sturges.counts.iter().sum::<usize>(), 100);
+    let fd = histogram_(&values, HistogramBins::FreedmanDiaconis, None).unwrap();
+    assert_eq!(fd.counts.len(), 5);
+    let auto = histogram_(&values, HistogramBins::Auto, None).unwrap();
+    assert_eq!(auto.counts.len(), 8);
+}
+
+#[test]
+fn test_histogram_errors() {
+    assert!(histogram_(&[1.0], HistogramBins::Count(0), None).is_err());
+    assert!(histogram_(&[1.0], HistogramBins::Edges(vec![1.0]), None).is_err());
+    assert!(histogram_(&[1.0], HistogramBins::Edges(vec![2.0, 1.0]), None).is_err());
+    assert!(histogram_(&[1.0], HistogramBins::Count(2), Some((2.0, 1.0))).is_err());
+    assert!(histogram_(&[1.0], HistogramBins::Count(2), Some((0.0, f64::INFINITY))).is_err());
+}
+
+#[test]
+fn test_digitize_basic() {
+    let edges = [0.0, 10.0, 20.0];
+    assert_eq!(
+        digitize_(&[-5.0, 0.0, 5.0, 10.0, 25.0], &edges),
+        Ok(vec![0, 1, 1, 2, 3])
+    );
+}
+
+#[test]
+fn test_digitize_unsorted_edges() {
+    assert!(digitize_(&[1.0], &[10.0, 0.0]).is_err());
+}
+
+// ==================== clamp_ / in_range_ Tests ====================
+#[test]
+fn test_clamp_scalar() {
+    assert_eq!(clamp_(-10i64, -5, 5), -5);
+    assert_eq!(clamp_(10i64, -5, 5), 5);
+    assert_eq!(clamp_(0.5f64, 0.0, 1.0), 0.5);
+    assert!(clamp_(f64::NAN, 0.0, 1.0).is_nan());
+}
+
+#[test]
+fn test_clamp_slice() {
+    assert_eq!(clamp_(&[-3i64, 0, 7][..], 0, 5), vec![0, 0, 5]);
+    assert_eq!(clamp_(&[0.5, 1.5][..], 0.0, 1.0), vec![0.5, 1.0]);
+}
+
+#[test]
+fn test_in_range() {
+    assert!(in_range_(3.0, 2.0, 4.0));
+    assert!(!in_range_(4.0, 2.0, 4.0));
+    assert!(in_range_(-3.0, -2.0, -6.0));
+    assert_eq!(
+        in_range_(&[1.0, 2.0, 3.0][..], 2.0, 3.0),
+        vec![false, true, false]
+    );
+}
+
+// ==================== range_ / linspace_ / times_ Tests ====================
+#[test]
+fn test_range_ints() {
+    assert_eq!(range_(0i64, 4, 1).unwrap(), vec![0, 1, 2, 3]);
+    assert_eq!(range_(0i64, -4, -1).unwrap(), vec![0, -1, -2, -3]);
+    assert_eq!(range_(0i64, 20, 5).unwrap(), vec![0, 5, 10, 15]);
+    assert!(range_(0i64, 4, -1).unwrap().is_empty());
+    assert!(range_(0i64, 4, 0).is_err());
+}
+
+#[test]
+fn test_range_floats_exact_steps() {
+    let values = range_(0.0, 1.0, 0.1).unwrap();
+    assert_eq!(values.len(), 10);
+    for (i, v) in values.iter().enumerate() {
+        assert_eq!(*v, i as f64 * 0.1);
+    }
+    assert_eq!(range_(0.0, 1.0, 0.25).unwrap(), vec![0.0, 0.25, 0.5, 0.75]);
+    assert!(range_(0.0, 1.0, f64::NAN).is_err());
+}
+
+#[test]
+fn test_range_too_long_is_an_error() {
+    assert!(range_(i64::MIN, i64::MAX, 1).is_err());
+    assert!(range_(0.0, 1e300, 1.0).is_err());
+    assert!(linspace_(0.0, 1.0, usize::MAX, true).is_err());
+}
+
+#[test]
+fn test_range_right() {
+    assert_eq!(range_right_(1i64, 5, 1).unwrap(), vec![4, 3, 2, 1]);
+}
+
+#[test]
+fn test_linspace() {
+    assert_eq!(
+        linspace_(0.0, 1.0, 5, true),
+        Ok(vec![0.0, 0.25, 0.5, 0.75, 1.0])
+    );
+    assert_eq!(
+        linspace_(0.0, 1.0, 4, false),
+        Ok(vec![0.0, 0.25, 0.5, 0.75])
+    );
+    assert_eq!(linspace_(2.0, 3.0, 1, true), Ok(vec![2.0]));
+    assert_eq!(linspace_(0.0, 1.0, 0, true), Ok(vec![]));
+}
+
+#[test]
+fn test_times() {
+    let squares: Result<Vec<usize>, ()> = times_(4, |i| Ok(i * i));
+    assert_eq!(squares.unwrap(), vec![0, 1, 4, 9]);
+    let failed: Result<Vec<usize>, &str> = times_(4, |i| if i == 2 { Err("stop") } else { Ok(i) });
+    assert_eq!(failed, Err("stop"));
+}
+
+// ==================== scaling Tests ====================
+#[test]
+fn test_normalize() {
+    assert_eq!(
+        normalize_(&[2.0, 4.0, 6.0], None).unwrap(),
+        vec![0.0, 0.5, 1.0]
+    );
+    assert!(normalize_(&[], None).unwrap().is_empty());
+}
+
+#[test]
+fn test_normalize_zero_range() {
+    assert!(matches!(
+        normalize_(&[5.0, 5.0], None),
+        Err(NumbersError::InvalidArgument(_))
+    ));
+    assert_eq!(normalize_(&[5.0, 5.0], Some(0.5)).unwrap(), vec![0.5, 0.5]);
+}
+
+#[test]
+fn test_normalize_ignores_nan() {
+    let result = normalize_(&[0.0, f64::NAN, 10.0], None).unwrap();
+    assert_eq!(result[0], 0.0);
+    assert!(result[1].is_nan());
+    assert_eq!(result[2], 1.0);
+    assert!(normalize_(&[f64::NAN], None).unwrap()[0].is_nan());
+}
+
+#[test]
+fn test_standardize() {
+    assert_eq!(
+        standardize_(&[1.0, 2.0, 3.0], None).unwrap(),
+        vec![-1.0, 0.0, 1.0]
+    );
+    assert!(standardize_(&[4.0], None).is_err());
+    assert_eq!(standardize_(&[4.0], Some(0.0)).unwrap(), vec![0.0]);
+}
+
+#[test]
+fn test_robust_scale() {
+    // Median 3.5, IQR 2.5: the outlier does not move the center.
+    let result = robust_scale_(&[1.0, 2.0, 3.0, 4.0, 5.0, 1000.0], None).unwrap();
+    assert_eq!(result[2], -0.2);
+    assert!(robust_scale_(&[1.0, 1.0, 1.0, 1.0], None).is_err());
+}
+
+#[test]
+fn test_rescale() {
+    assert_eq!(
+        rescale_(&[0.0, 5.0, 10.0], -1.0, 1.0, None).unwrap(),
+        vec![-1.0, 0.0, 1.0]
+    );
+    assert_eq!(
+        rescale_(&[3.0, 3.0], 10.0, 20.0, Some(15.0)).unwrap(),
+        vec![15.0, 15.0]
+    );
+}
+
+// ==================== argmin_ / argmax_ / min_max_ / top_k_ Tests ====================
+#[test]
+fn test_argmax_argmin_first_on_ties() {
+    assert_eq!(argmax_(&[3, 9, 2, 9]), Some(1));
+    assert_eq!(argmin_(&[3, 1, 2, 1]), Some(1));
+    assert_eq!(argmax_::<i64>(&[]), None);
+}
+
+#[test]
+fn test_argmax_nan_wins() {
+    assert_eq!(argmax_(&[1.0, f64::NAN, 5.0]), Some(1));
+    assert_eq!(argmin_(&[f64::NAN, 0.0]), Some(0));
+}
+
+#[test]
+fn test_min_max() {
+    assert_eq!(min_max_(&[3, 1, 4, 1, 5]), Some((1, 5)));
+    assert_eq!(min_max_(&[2.0]), Some((2.0, 2.0)));
+    assert_eq!(min_max_::<f64>(&[]), None);
+    let (lo, hi) = min_max_(&[1.0, f64::NAN, 3.0]).unwrap();
+    assert!(lo.is_nan() && hi.is_nan());
+}
+
+#[test]
+fn test_top_k() {
+    assert_eq!(top_k_(&[5, 1, 9, 3, 7], 3, true), vec![2, 4, 0]);
+    assert_eq!(top_k_(&[5, 1, 9, 3, 7], 2, false), vec![1, 3]);
+    assert_eq!(top_k_(&[5, 1], 10, true), vec![0, 1]);
+    assert!(top_k_(&[5, 1], 0, true).is_empty());
+}
+
+#[test]
+fn test_top_k_ties_and_nan() {
+    assert_eq!(top_k_(&[2, 3, 2, 3, 2], 3, true), vec![1, 3, 0]);
+    assert_eq!(top_k_(&[2, 3, 2, 3, 2], 2, false), vec![0, 2]);
+    assert_eq!(top_k_(&[1.0, f64::NAN, 2.0], 3, true), vec![2, 0]);
+}
+
+#[test]
+fn test_exact_number_ordering() {
+    let ts = 1_700_000_000_000_000_000i128;
+    let keys = [ExactNumber::Int(ts), ExactNumber::Int(ts + 100)];
+    assert_eq!(argmax_(&keys), Some(1));
+    assert_eq!(top_k_(&keys, 1, true), vec![1]);
+    // `2**53 + 1` rounds to `2**53` as a float but is still larger.
+    let big = (1i128 << 53) + 1;
+    assert!(ExactNumber::Int(big) > ExactNumber::Float(9007199254740992.0));
+    assert!(ExactNumber::Float(9007199254740992.0) < ExactNumber::Int(big));
+    assert_eq!(ExactNumber::Int(3), ExactNumber::Float(3.0));
+    assert!(ExactNumber::Int(i128::MAX) < ExactNumber::Float(f64::MAX));
+    assert!(ExactNumber::Int(1)
+        .partial_cmp(&ExactNumber::Float(f64::NAN))
+        .is_none());
+}
+
+// ==================== integer math Tests ====================
+#[test]
+fn test_gcd_lcm() {
+    assert_eq!(gcd_(&[12, 18, 24]), Some(6));
+    assert_eq!(gcd_(&[-4, 6]), Some(2));
+    assert_eq!(gcd_(&[]), Some(0));
+    assert_eq!(gcd_(&[i64::MIN]), None);
+    assert_eq!(lcm_(&[4, 6, 10]), Some(60));
+    assert_eq!(lcm_(&[0, 5]), Some(0));
+    assert_eq!(lcm_(&[]), Some(1));
+    assert_eq!(lcm_(&[i64::MAX, i64::MAX - 1]), None);
+}
+
+#[test]
+fn test_factorial() {
+    assert_eq!(factorial_(0), Some(1));
+    assert_eq!(factorial_(5), Some(120));
+    assert_eq!(factorial_(20), Some(2_432_902_008_176_640_000));
+    assert_eq!(factorial_(21), None);
+}
+
+#[test]
+fn test_n_choose_k() {
+    assert_eq!(n_choose_k_(5, 2), Some(10));
+    assert_eq!(n_choose_k_(5, 0), Some(1));
+    assert_eq!(n_choose_k_(3, 5), Some(0));
+    assert_eq!(n_choose_k_(62, 31), Some(465_428_353_255_261_088));
+    assert_eq!(n_choose_k_(100, 50), None);
+}
+
+#[test]
+fn test_mod_pow() {
+    assert_eq!(mod_pow_(3, 200, 13).unwrap(), 9);
+    assert_eq!(mod_pow_(-2, 3, 5).unwrap(), 2);
+    assert_eq!(mod_pow_(5, 0, 1).unwrap(), 0);
+    assert_eq!(
+        mod_pow_(i64::MAX, u64::MAX, i64::MAX - 24).unwrap(),
+        3_345_919_721_658_737_851
+    );
+    assert!(mod_pow_(2, 3, 0).is_err());
+}
+
+#[test]
+fn test_is_prime() {
+    let small: Vec<i64> = (0..30).filter(|&n| is_prime_(n)).collect();
+    assert_eq!(Ok(small), primes_up_to_(29));
+    assert!(is_prime_(2_305_843_009_213_693_951)); // 2^61 - 1
+    assert!(!is_prime_(3_215_031_751)); // strong pseudoprime to bases 2, 3, 5 and 7
+    assert!(!is_prime_(-7));
+}
+
+#[test]
+fn test_primes_up_to() {
+    assert_eq!(
+        primes_up_to_(30),
+        Ok(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29])
+    );
+    assert_eq!(primes_up_to_(1), Ok(vec![]));
+    assert_eq!(primes_up_to_(100_000).unwrap().len(), 9592);
+    assert!(primes_up_to_(usize::MAX).is_err());
+}
+
+// ==================== interpolation Tests ====================
+#[test]
+fn test_lerp() {
+    assert_eq!(lerp_(0.0, 10.0, 0.25), 2.5);
+    assert_eq!(lerp_(10.0, 20.0, 1.5), 25.0);
+}
+
+#[test]
+fn test_interp_like_numpy() {
+    let xp = [1.0, 2.0, 3.0];
+    let fp = [3.0, 2.0, 0.0];
+    assert_eq!(
+        interp_(&[0.0, 1.0, 1.5, 2.5, 5.0], &xp, &fp).unwrap(),
+        vec![3.0, 3.0, 2.5, 1.0, 0.0]
+    );
+    assert!(interp_(&[f64::NAN], &xp, &fp).unwrap()[0].is_nan());
+}
+
+#[test]
+fn test_interp_validation() {
+    assert!(matches!(
+        interp_(&[1.0], &[3.0, 1.0], &[0.0, 1.0]),
+        Err(NumbersError::InvalidArgument(msg)) if msg.contains("xp")
+    ));
+    assert!(interp_(&[1.0], &[1.0, 2.0], &[0.0]).is_err());
+    assert!(interp_(&[1.0], &[], &[]).is_err());
+}
+
+#[test]
+fn test_fill_gaps() {
+    let values = [None, Some(1.0), None, None, Some(4.0), None];
+    assert_eq!(
+        fill_gaps_(&values, FillMethod::Linear),
+        vec![None, Some(1.0), Some(2.0), Some(3.0), Some(4.0), None]
+    );
+    assert_eq!(
+        fill_gaps_(&values, FillMethod::Ffill),
+        vec![None, Some(1.0), Some(1.0), Some(1.0), Some(4.0), Some(4.0)]
+    );
+    assert_eq!(
+        fill_gaps_(&values, FillMethod::Bfill),
+        vec![Some(1.0), Some(1.0), Some(4.0), Some(4.0), Some(4.0), None]
+    );
+    assert!(fill_gaps_(&[], FillMethod::Bfill).is_empty());
+    assert!("nearest".parse::<FillMethod>().is_err());
+}
+
+#[test]
+fn test_resample() {
+    let points = [(0.0, 1.0), (30.0, 3.0), (130.0, 5.0), (61.0, 2.0)];
+    assert_eq!(
+        resample_(&points, 60.0, ResampleAgg::Mean).unwrap(),
+        vec![(0.0, Some(2.0)), (60.0, Some(2.0)), (120.0, Some(5.0))]
+    );
+    assert_eq!(
+        resample_(&[(0.0, 1.0), (190.0, 2.0)], 60.0, ResampleAgg::Sum).unwrap(),
+        vec![
+            (0.0, Some(1.0)),
+            (60.0, Some(0.0)),
+            (120.0, Some(0.0)),
+            (180.0, Some(2.0))
+        ]
+    );
+    assert_eq!(
+        resample_(&[(0.0, 1.0), (120.0, 2.0)], 60.0, ResampleAgg::Max).unwrap(),
+        vec![(0.0, Some(1.0)), (60.0, None), (120.0, Some(2.0))]
+    );
+}
+
+#[test]
+fn test_resample_first_last_and_negative_times() {
+    let points = [(-5.0, 1.0), (-1.0, 2.0), (1.0, 3.0)];
+    assert_eq!(
+        resample_(&points, 10.0, ResampleAgg::First).unwrap(),
+        vec![(-10.0, Some(1.0)), (0.0, Some(3.0))]
+    );
+    assert_eq!(
+        resample_(&points, 10.0, ResampleAgg::Last).unwrap(),
+        vec![(-10.0, Some(2.0)), (0.0, Some(3.0))]
+    );
+    assert!(resample_(&[], 10.0, ResampleAgg::Mean).unwrap().is_empty());
+    assert!(resample_(&points, 0.0, ResampleAgg::Mean).is_err());
+}
+
+#[test]
+fn test_resample_int_timestamps_are_exact() {
+    let base = 1_700_000_000_000_000_001i64;
+    assert_eq!(
+        resample_(&[(base, 1.0), (base + 1, 2.0)], 2, ResampleAgg::Count).unwrap(),
+        vec![(base - 1, Some(1.0)), (base + 1, Some(1.0))]
+    );
+    assert!(resample_(&[(0i64, 1.0)], 0, ResampleAgg::Mean).is_err());
+}
+
+#[test]
+fn test_resample_too_many_buckets() {
+    let points = [(0i64, 1.0), (1_700_000_000_000, 2.0)];
+    assert!(matches!(
+        resample_(&points, 1, ResampleAgg::Mean),
+        Err(NumbersError::InvalidArgument(_))
+    ));
+    assert!(resample_(&[(0.0, 1.0), (1e300, 2.0)], 1e-300, ResampleAgg::Mean).is_err());
+}
@@ -0,0 +1,124 @@
+use rustdash::core::random::{sample_, sample_size_, shuffle_, weighted_choice_, Reservoir, Rng};
+
+// ==================== Rng Tests ====================
+#[test]
+fn test_rng_reference_stream() {
+    // xoshiro256** seeded through SplitMix64; pins the stream across platforms.
+    let mut rng = Rng::new(0);
+    assert_eq!(rng.next_u64(), 0x99ec_5f36_cb75_f2b4);
+    assert_eq!(rng.next_u64(), 0xbf6e_1f78_4956_452a);
+    assert_eq!(rng.next_u64(), 0x1a5f_849d_4933_e6e0);
+}
+
+#[test]
+fn test_rng_same_seed_same_stream() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    for _ in 0..100 {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+}
+
+#[test]
+fn test_rng_float_in_unit_interval() {
+    let mut rng = Rng::new(1);
+    for _ in 0..1000 {
+        let x = rng.next_f64();
+        assert!((0.0..1.0).contains(&x));
+    }
+}
+
+#[test]
+fn test_rng_int_between_inclusive() {
+    let mut rng = Rng::new(2);
+    let mut seen = [false; 6];
+    for _ in 0..1000 {
+        let x = rng.int_between(1, 6);
+        assert!((1..=6).contains(&x));
+        seen[(x - 1) as usize] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+    assert_eq!(rng.int_between(3, 3), 3);
+    // The full i64 range must not overflow.
+    rng.int_between(i64::MIN, i64::MAX);
+}
+
+#[test]
+fn test_rng_float_between_swapped_bounds() {
+    let mut rng = Rng::new(3);
+    for _ in 0..100 {
+        let x = rng.float_between(5.0, -5.0);
+        assert!((-5.0..5.0).contains(&x));
+    }
+}
+
+// ==================== Sampling Tests ====================
+#[test]
+fn test_shuffle_is_permutation() {
+    let mut rng = Rng::new(4);
+    let mut items: Vec<u32> = (0..50).collect();
+    shuffle_(&mut rng, &mut items);
+    assert_ne!(items, (0..50).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..50).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_sample() {
+    let mut rng = Rng::new(5);
+    assert!(sample_(&mut rng, &[1, 2, 3]).unwrap() < 3);
+    assert_eq!(sample_::<i32>(&mut rng, &[]), None);
+}
+
+#[test]
+fn test_sample_size_distinct_and_capped() {
+    let mut rng = Rng::new(6);
+    let picked = sample_size_(&mut rng, 10, 4);
+    assert_eq!(picked.len(), 4);
+    let mut sorted = picked.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), 4);
+    assert_eq!(sample_size_(&mut rng, 3, 10).len(), 3);
+}
+
+#[test]
+fn test_weighted_choice() {
+    let mut rng = Rng::new(7);
+    for _ in 0..200 {
+        assert_ne!(
+            weighted_choice_(&mut rng, &[1.0, 0.0, 1.0]).unwrap(),
+            Some(1)
+        );
+    }
+    assert_eq!(weighted_choice_(&mut rng, &[]).unwrap(), None);
+    assert!(weighted_choice_(&mut rng, &[0.0, 0.0]).is_err());
+    assert!(weighted_choice_(&mut rng, &[1.0, -1.0]).is_err());
+    assert!(weighted_choice_(&mut rng, &[1.0, f64::NAN]).is_err());
+}
+
+#[test]
+fn test_reservoir_uniform() {
+    let mut rng = Rng::new(8);
+    let mut counts = [0usize; 10];
+    for _ in 0..5000 {
+        let mut reservoir = Reservoir::new(2);
+        for i in 0..10 {
+            reservoir.push(&mut rng, i);
+        }
+        for i in reservoir.into_vec() {
+            counts[i] += 1;
+        }
+    }
+    // Each item is kept with probability 2/10, i.e. about 1000 times.
+    assert!(counts.iter().all(|&c| (850..1150).contains(&c)));
+}
+
+#[test]
+fn test_reservoir_short_stream() {
+    let mut rng = Rng::new(9);
+    let mut reservoir = Reservoir::new(5);
+    reservoir.push(&mut rng, "a");
+    reservoir.push(&mut rng, "b");
+    assert_eq!(reservoir.into_vec(), vec!["a", "b"]);
+}
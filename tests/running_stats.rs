@@ -0,0 +1,128 @@
+use rustdash::core::running_stats::{RunningStats, TDigest};
+
+// ==================== RunningStats Tests ====================
+
+#[test]
+fn test_running_stats_empty() {
+    let stats = RunningStats::default();
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.variance(), None);
+    assert_eq!(stats.min(), None);
+    assert_eq!(stats.max(), None);
+}
+
+#[test]
+fn test_running_stats_basic() {
+    let mut stats = RunningStats::default();
+    stats.extend(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+    assert_eq!(stats.count(), 8);
+    assert!((stats.mean().unwrap() - 5.0).abs() < 1e-12);
+    assert!((stats.variance().unwrap() - 32.0 / 7.0).abs() < 1e-12);
+    assert_eq!(stats.min(), Some(2.0));
+    assert_eq!(stats.max(), Some(9.0));
+}
+
+#[test]
+fn test_running_stats_single_value() {
+    let mut stats = RunningStats::default();
+    stats.push(3.0);
+    assert_eq!(stats.mean(), Some(3.0));
+    assert_eq!(stats.variance(), None);
+}
+
+#[test]
+fn test_running_stats_ignores_nan() {
+    let mut stats = RunningStats::default();
+    stats.extend(&[1.0, f64::NAN, 3.0]);
+    assert_eq!(stats.count(), 2);
+    assert_eq!(stats.mean(), Some(2.0));
+}
+
+#[test]
+fn test_running_stats_merge_matches_single_pass() {
+    let values: Vec<f64> = (0..1000).map(|x| (x as f64 * 0.37).sin() * 10.0).collect();
+    let mut whole = RunningStats::default();
+    whole.extend(&values);
+
+    let mut left = RunningStats::default();
+    let mut right = RunningStats::default();
+    left.extend(&values[..300]);
+    right.extend(&values[300..]);
+    left.merge(&right);
+
+    assert_eq!(left.count(), whole.count());
+    assert!((left.mean().unwrap() - whole.mean().unwrap()).abs() < 1e-10);
+    assert!((left.variance().unwrap() - whole.variance().unwrap()).abs() < 1e-10);
+    assert_eq!(left.min(), whole.min());
+    assert_eq!(left.max(), whole.max());
+}
+
+#[test]
+fn test_running_stats_merge_into_empty() {
+    let mut empty = RunningStats::default();
+    let mut other = RunningStats::default();
+    other.extend(&[1.0, 2.0, 3.0]);
+    empty.merge(&other);
+    assert_eq!(empty.mean(), Some(2.0));
+    assert_eq!(empty.variance(), Some(1.0));
+}
+
+#[test]
+fn test_running_stats_quantiles() {
+    let mut stats = RunningStats::default();
+    let values: Vec<f64> = (1..=10_000).map(|x| x as f64).collect();
+    stats.extend(&values);
+    let median = stats.quantile(0.5).unwrap().unwrap();
+    let p99 = stats.quantile(0.99).unwrap().unwrap();
+    assert!((median - 5000.0).abs() < 50.0);
+    assert!((p99 - 9900.0).abs() < 20.0);
+    assert_eq!(stats.quantile(0.0).unwrap(), Some(1.0));
+    assert_eq!(stats.quantile(1.0).unwrap(), Some(10_000.0));
+}
+
+#[test]
+fn test_running_stats_quantile_out_of_range() {
+    let mut stats = RunningStats::default();
+    assert!(stats.quantile(1.5).is_err());
+    assert_eq!(stats.quantile(0.5).unwrap(), None);
+}
+
+#[test]
+fn test_running_stats_bytes_round_trip() {
+    let mut stats = RunningStats::new(50.0);
+    stats.extend(&(0..500).map(|x| x as f64).collect::<Vec<_>>());
+    let mut restored = RunningStats::from_bytes(&stats.to_bytes()).unwrap();
+    assert_eq!(restored.count(), 500);
+    assert_eq!(restored.mean(), stats.mean());
+    assert_eq!(restored.variance(), stats.variance());
+    assert_eq!(
+        restored.quantile(0.5).unwrap(),
+        stats.quantile(0.5).unwrap()
+    );
+}
+
+#[test]
+fn test_running_stats_from_invalid_bytes() {
+    assert!(RunningStats::from_bytes(&[0u8; 7]).is_err());
+    let mut state = RunningStats::new(50.0).to_bytes();
+    for compression in [0.0, -5.0, f64::NAN, f64::INFINITY] {
+        state[40..48].copy_from_slice(&compression.to_le_bytes());
+        assert!(RunningStats::from_bytes(&state).is_err());
+    }
+}
+
+// ==================== TDigest Tests ====================
+
+#[test]
+fn test_tdigest_merge() {
+    let mut left = TDigest::new(100.0);
+    let mut right = TDigest::new(100.0);
+    for x in 0..5000 {
+        left.push(x as f64);
+        right.push((x + 5000) as f64);
+    }
+    left.merge(&right);
+    let median = left.quantile(0.5).unwrap();
+    assert!((median - 5000.0).abs() < 50.0);
+}
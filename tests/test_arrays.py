@@ -1,5 +1,11 @@
 import rustdash
 
+USERS = [
+    {"name": "ann", "active": True, "profile": {"age": 34, "role": "admin", "tags": ["a", "b"]}},
+    {"name": "bob", "active": False, "profile": {"age": 27, "role": "user", "tags": ["b"]}},
+    {"name": "cy", "active": True, "profile": {"age": 41, "role": "user", "tags": []}},
+]
+
 
 # ==================== chunk Tests ====================
 
@@ -22,6 +28,113 @@ def test_chunk_strings():
     assert rustdash.chunk(["a", "b", "c", "d"], 2) == [["a", "b"], ["c", "d"]]
 
 
+def test_chunk_fill_pads_last_chunk():
+    assert rustdash.chunk([1, 2, 3, 4, 5], 2, fill=0) == [[1, 2], [3, 4], [5, 0]]
+    assert rustdash.chunk([1, 2, 3, 4], 2, fill=0) == [[1, 2], [3, 4]]
+
+def test_chunk_fill_none_pads():
+    assert rustdash.chunk([1, 2, 3], 2, fill=None) == [[1, 2], [3, None]]
+    assert rustdash.chunk([1, 2, 3], 2) == [[1, 2], [3]]
+    try:
+        rustdash.chunk([1, 2, 3], 2, fill=None, strict=True)
+        assert False
+    except ValueError:
+        pass
+
+def test_chunk_huge_size():
+    assert rustdash.chunk([1, 2, 3], 2**62) == [[1, 2, 3]]
+    try:
+        rustdash.chunk([1, 2, 3], 2**62, fill=0)
+        assert False
+    except MemoryError:
+        pass
+
+def test_chunk_strict():
+    assert rustdash.chunk([1, 2, 3, 4], 2, strict=True) == [[1, 2], [3, 4]]
+    try:
+        rustdash.chunk([1, 2, 3], 2, strict=True)
+        assert False
+    except ValueError:
+        pass
+
+def test_chunk_fill_and_strict_raises():
+    try:
+        rustdash.chunk([1, 2, 3], 2, fill=0, strict=True)
+        assert False
+    except ValueError:
+        pass
+
+def test_chunk_bad_size_raises():
+    for size in (0, -1):
+        try:
+            rustdash.chunk([1, 2, 3], size)
+            assert False
+        except ValueError:
+            pass
+
+def test_chunk_iterable():
+    assert rustdash.chunk(range(5), 3) == [[0, 1, 2], [3, 4]]
+
+
+# ==================== windowed Tests ====================
+
+def test_windowed():
+    assert rustdash.windowed([1, 2, 3, 4], 2) == [[1, 2], [2, 3], [3, 4]]
+
+def test_windowed_step():
+    assert rustdash.windowed([1, 2, 3, 4, 5], 2, step=2) == [[1, 2], [3, 4]]
+
+def test_windowed_partial():
+    assert rustdash.windowed([1, 2, 3, 4, 5], 3, step=2, partial=True) == [[1, 2, 3], [3, 4, 5], [5]]
+
+def test_windowed_size_larger_than_array():
+    assert rustdash.windowed([1, 2], 3) == []
+    assert rustdash.windowed([1, 2], 3, partial=True) == [[1, 2], [2]]
+
+def test_windowed_bad_size_or_step_raises():
+    for size, step in ((0, 1), (2, 0), (-1, 1)):
+        try:
+            rustdash.windowed([1, 2, 3], size, step=step)
+            assert False
+        except ValueError:
+            pass
+
+
+# ==================== pairwise Tests ====================
+
+def test_pairwise():
+    assert rustdash.pairwise([1, 2, 3]) == [[1, 2], [2, 3]]
+
+def test_pairwise_short():
+    assert rustdash.pairwise([1]) == []
+    assert rustdash.pairwise([]) == []
+
+
+# ==================== split_at Tests ====================
+
+def test_split_at():
+    assert rustdash.split_at([1, 2, 3, 4], 1) == [[1], [2, 3, 4]]
+
+def test_split_at_negative_index():
+    assert rustdash.split_at([1, 2, 3, 4], -1) == [[1, 2, 3], [4]]
+
+def test_split_at_out_of_range_clamps():
+    assert rustdash.split_at([1, 2], 5) == [[1, 2], []]
+    assert rustdash.split_at([1, 2], -5) == [[], [1, 2]]
+
+
+# ==================== split_when Tests ====================
+
+def test_split_when():
+    assert rustdash.split_when([1, 2, 3, 3, 2, 5], lambda a, b: b < a) == [[1, 2, 3, 3], [2, 5]]
+
+def test_split_when_runs():
+    assert rustdash.split_when([1, 2, 4, 5, 7], lambda a, b: b != a + 1) == [[1, 2], [4, 5], [7]]
+
+def test_split_when_never_and_empty():
+    assert rustdash.split_when([1, 2], lambda a, b: False) == [[1, 2]]
+    assert rustdash.split_when([], lambda a, b: True) == []
+
 # ==================== compact Tests ====================
 
 def test_compact_with_none():
@@ -40,6 +153,25 @@ def test_compact_strings():
     assert rustdash.compact(["hello", None, "world"]) == ["hello", "world"]
 
 
+# ==================== flatten Tests ====================
+
+def test_flatten_one_level():
+    assert rustdash.flatten([1, [2, [3, [4]]], 5]) == [1, 2, [3, [4]], 5]
+
+def test_flatten_depth():
+    assert rustdash.flatten([1, [2, [3, [4]]], 5], depth=2) == [1, 2, 3, [4], 5]
+    assert rustdash.flatten([1, [2, [3, [4]]]], depth=0) == [1, [2, [3, [4]]]]
+
+def test_flatten_leaves_tuples_by_default():
+    assert rustdash.flatten([(1, 2), [3]]) == [(1, 2), 3]
+
+def test_flatten_types():
+    assert rustdash.flatten([(1, 2), [3, (4,)]], depth=2, types=(list, tuple)) == [1, 2, 3, 4]
+
+def test_flatten_accepts_iterable_input():
+    assert rustdash.flatten(([1], [2])) == [1, 2]
+
+
 # ==================== flatten_deep Tests ====================
 
 def test_flatten_deep_nested():
@@ -61,6 +193,39 @@ def test_flatten_deep_deeply_nested():
     assert rustdash.flatten_deep([[[1, 2], [3]], [[4], [5, 6]]]) == [1, 2, 3, 4, 5, 6]
 
 
+def test_flatten_deep_types_iterable():
+    from collections.abc import Iterable
+    data = [1, (2, {3}), (x for x in [4, [5]]), "ab", b"cd"]
+    assert rustdash.flatten_deep(data, types=Iterable) == [1, 2, 3, 4, 5, "ab", b"cd"]
+
+def test_flatten_deep_strings_are_atoms():
+    assert rustdash.flatten_deep([["ab"], "cd"], types=(list, str)) == ["ab", "cd"]
+
+def test_flatten_atom_or_scalar_input():
+    assert rustdash.flatten_deep("abc") == ["abc"]
+    assert rustdash.flatten_deep(b"ab") == [b"ab"]
+    assert rustdash.flatten_deep(5) == [5]
+    assert rustdash.flatten("abc", types=(list, str)) == ["abc"]
+
+def test_flatten_deep_very_deep_nesting():
+    data = [0]
+    for i in range(1, 100_000):
+        data = [data, i]
+    assert rustdash.flatten_deep(data) == list(range(100_000))
+
+def test_flatten_deep_self_reference_raises():
+    data = [1]
+    data.append(data)
+    try:
+        rustdash.flatten_deep(data)
+        assert False
+    except ValueError:
+        pass
+
+def test_flatten_deep_repeated_sibling_is_not_a_cycle():
+    inner = [1]
+    assert rustdash.flatten_deep([inner, inner]) == [1, 1]
+
 # ==================== unique Tests ====================
 
 def test_unique_basic():
@@ -81,6 +246,66 @@ def test_unique_preserves_order():
 def test_unique_strings():
     assert rustdash.unique(["a", "b", "a", "c", "b"]) == ["a", "b", "c"]
 
+def test_unique_floats_and_mixed():
+    assert rustdash.unique([1.5, 0.0, -0.0, 1.5]) == [1.5, 0.0]
+    assert rustdash.unique([1, "1", 1.0, None, None]) == [1, "1", None]
+
+def test_unique_dicts():
+    assert rustdash.unique([{"a": 1}, {"a": 1}, {"a": 2}]) == [{"a": 1}, {"a": 2}]
+
+def test_unique_dict_key_order_ignored():
+    result = rustdash.unique([{"a": 1, "b": 2}, {"b": 2, "a": 1}])
+    assert result == [{"a": 1, "b": 2}]
+
+def test_unique_nested_structures():
+    data = [[1, [2, 3]], [1, [2, 3]], ({"x": [1]},), ({"x": [1]},), {1, 2}, {2, 1}]
+    assert rustdash.unique(data) == [[1, [2, 3]], ({"x": [1]},), {1, 2}]
+
+def test_unique_unhashable_object_raises():
+    class Unhashable:
+        __hash__ = None
+    try:
+        rustdash.unique([Unhashable()])
+        assert False
+    except TypeError:
+        pass
+
+def test_unique_keep_last():
+    assert rustdash.unique([3, 1, 3, 2, 1], keep="last") == [3, 2, 1]
+
+def test_unique_invalid_keep():
+    try:
+        rustdash.unique([1], keep="middle")
+        assert False
+    except ValueError:
+        pass
+
+
+# ==================== unique_by Tests ====================
+
+def test_unique_by_path():
+    records = [{"id": 1, "v": "a"}, {"id": 2, "v": "b"}, {"id": 1, "v": "c"}]
+    assert rustdash.unique_by(records, "id") == [{"id": 1, "v": "a"}, {"id": 2, "v": "b"}]
+
+def test_unique_by_keep_last():
+    records = [{"id": 1, "v": "a"}, {"id": 2, "v": "b"}, {"id": 1, "v": "c"}]
+    assert rustdash.unique_by(records, "id", keep="last") == [{"id": 2, "v": "b"}, {"id": 1, "v": "c"}]
+
+def test_unique_by_callable():
+    assert rustdash.unique_by([2.1, 1.2, 2.3], int) == [2.1, 1.2]
+
+def test_unique_by_unhashable_keys():
+    records = [{"tags": ["x"]}, {"tags": ["y"]}, {"tags": ["x"]}]
+    assert rustdash.unique_by(records, "tags") == [{"tags": ["x"]}, {"tags": ["y"]}]
+
+
+# ==================== unique_with Tests ====================
+
+def test_unique_with():
+    same_lower = lambda a, b: a.lower() == b.lower()
+    assert rustdash.unique_with(["A", "b", "a", "B"], same_lower) == ["A", "b"]
+    assert rustdash.unique_with(["A", "b", "a", "B"], same_lower, keep="last") == ["a", "B"]
+
 
 # ==================== group_by Tests ====================
 
@@ -104,6 +329,297 @@ def test_group_by_single_group():
     assert len(result) == 1
     assert result[0] == [2, 4, 6]
 
+def test_group_by_path():
+    result = rustdash.group_by(USERS, "profile.role")
+    assert [u["name"] for u in result["admin"]] == ["ann"]
+    assert [u["name"] for u in result["user"]] == ["bob", "cy"]
+
+def test_group_by_matches():
+    result = rustdash.group_by(USERS, {"active": True})
+    assert [u["name"] for u in result[True]] == ["ann", "cy"]
+
+
+
+# ==================== count_by Tests ====================
+
+def test_count_by_callable():
+    assert rustdash.count_by([6.1, 4.2, 6.3], int) == {6: 2, 4: 1}
+
+def test_count_by_path():
+    assert rustdash.count_by(USERS, "profile.role") == {"admin": 1, "user": 2}
+
+def test_count_by_missing_path():
+    assert rustdash.count_by([{"a": 1}, {}, {}], "a") == {1: 1, None: 2}
+
+def test_count_by_empty():
+    assert rustdash.count_by([], "a") == {}
+
+
+# ==================== key_by Tests ====================
+
+def test_key_by_path_keeps_last():
+    rows = [{"id": "a", "v": 1}, {"id": "b", "v": 2}, {"id": "a", "v": 3}]
+    assert rustdash.key_by(rows, "id") == {"a": {"id": "a", "v": 3}, "b": {"id": "b", "v": 2}}
+
+def test_key_by_callable():
+    assert rustdash.key_by(["apple", "bob"], len) == {5: "apple", 3: "bob"}
+
+
+# ==================== partition Tests ====================
+
+def test_partition_callable():
+    assert rustdash.partition([1, 2, 3, 4], lambda x: x % 2) == [[1, 3], [2, 4]]
+
+def test_partition_matches():
+    active, inactive = rustdash.partition(USERS, {"active": True})
+    assert [u["name"] for u in active] == ["ann", "cy"]
+    assert [u["name"] for u in inactive] == ["bob"]
+
+def test_partition_path():
+    assert rustdash.partition([{"ok": 1}, {"ok": 0}, {}], "ok") == [[{"ok": 1}], [{"ok": 0}, {}]]
+
+def test_partition_empty():
+    assert rustdash.partition([], lambda x: x) == [[], []]
+
+
+# ==================== aggregate Tests ====================
+
+ORDERS = [
+    {"region": "eu", "product": "a", "amount": 10, "price": 2.5},
+    {"region": "us", "product": "a", "amount": 5, "price": 3.0},
+    {"region": "eu", "product": "b", "amount": 7, "price": 1.5},
+    {"region": "eu", "product": "a", "amount": 3, "price": None},
+]
+
+def test_aggregate_single_key():
+    result = rustdash.aggregate(ORDERS, by="region", aggs={
+        "total": ("amount", "sum"),
+        "n": (None, "count"),
+        "avg": ("price", "mean"),
+    })
+    assert result == {
+        "eu": {"total": 20, "n": 3, "avg": 2.0},
+        "us": {"total": 5, "n": 1, "avg": 3.0},
+    }
+    assert isinstance(result["eu"]["total"], int)
+
+def test_aggregate_multi_key():
+    result = rustdash.aggregate(ORDERS, by=["region", "product"], aggs={"total": ("amount", "sum")})
+    assert result == {("eu", "a"): {"total": 13}, ("us", "a"): {"total": 5}, ("eu", "b"): {"total": 7}}
+
+def test_aggregate_records():
+    result = rustdash.aggregate(
+        ORDERS, by=["region", lambda o: o["amount"] > 5], aggs={"n": (None, "count")}, as_records=True
+    )
+    assert result == [
+        {"region": "eu", "key1": True, "n": 2},
+        {"region": "us", "key1": False, "n": 1},
+        {"region": "eu", "key1": False, "n": 1},
+    ]
+
+def test_aggregate_records_single_callable_key():
+    result = rustdash.aggregate([1, 2, 3], by=lambda x: x % 2, aggs={"s": (lambda x: x, "sum")}, as_records=True)
+    assert result == [{"key": 1, "s": 4}, {"key": 0, "s": 2}]
+
+def test_aggregate_no_key():
+    result = rustdash.aggregate(ORDERS, by=None, aggs={"max": ("amount", "max"), "min": ("price", "min")})
+    assert result == {None: {"max": 10, "min": 1.5}}
+
+def test_aggregate_statistics():
+    rows = [{"g": 1, "v": v} for v in (1, 2, 3, 4)]
+    result = rustdash.aggregate(rows, by="g", aggs={
+        "median": ("v", "median"), "var": ("v", "var"), "std": ("v", "std"),
+    })[1]
+    assert result["median"] == 2.5
+    assert abs(result["var"] - 5 / 3) < 1e-12
+    assert abs(result["std"] - (5 / 3) ** 0.5) < 1e-12
+
+def test_aggregate_first_last_and_counts_skip_none():
+    result = rustdash.aggregate(ORDERS, by="region", aggs={
+        "first": ("product", "first"),
+        "last_price": ("price", "last"),
+        "priced": ("price", "count"),
+        "row": (None, "first"),
+    })
+    assert result["eu"]["first"] == "a"
+    assert result["eu"]["last_price"] == 1.5
+    assert result["eu"]["priced"] == 2
+    assert result["eu"]["row"] is ORDERS[0]
+
+def test_aggregate_empty_values():
+    result = rustdash.aggregate([{"g": 1, "v": None}], by="g", aggs={
+        "sum": ("v", "sum"), "mean": ("v", "mean"), "std": ("v", "std"),
+    })
+    assert result == {1: {"sum": 0, "mean": None, "std": None}}
+
+def test_aggregate_mixed_ints_and_floats():
+    result = rustdash.aggregate([1, 2.5, 3], by=None, aggs={"s": (lambda x: x, "sum")})
+    assert result == {None: {"s": 6.5}}
+
+def test_aggregate_invalid_specs():
+    for aggs, exc in (
+        ({"x": ("amount", "total")}, ValueError),
+        ({"x": (None, "sum")}, ValueError),
+        ({"x": "amount"}, TypeError),
+    ):
+        try:
+            rustdash.aggregate(ORDERS, by="region", aggs=aggs)
+            assert False
+        except exc:
+            pass
+
+def test_aggregate_non_numeric_raises():
+    try:
+        rustdash.aggregate(ORDERS, by="region", aggs={"x": ("product", "sum")})
+        assert False
+    except TypeError:
+        pass
+
+def test_aggregate_large_ints_stay_exact():
+    rows = [{"v": 2**62}, {"v": 2**62}, {"v": 1}, {"v": 2**100}]
+    result = rustdash.aggregate(rows[:3], by=None, aggs={"s": ("v", "sum"), "m": ("v", "max")})
+    assert result == {None: {"s": 2**63 + 1, "m": 2**62}}
+    result = rustdash.aggregate(rows, by=None, aggs={"s": ("v", "sum"), "m": ("v", "max")})
+    assert result == {None: {"s": 2**100 + 2**63 + 1, "m": 2**100}}
+
+def test_aggregate_rejects_bools():
+    try:
+        rustdash.aggregate([{"v": True}, {"v": 2}], by=None, aggs={"s": ("v", "sum")})
+        assert False
+    except TypeError:
+        pass
+    assert rustdash.aggregate([{"v": True}], by=None, aggs={"n": ("v", "count")}) == {None: {"n": 1}}
+
+def test_aggregate_records_name_clash_raises():
+    try:
+        rustdash.aggregate([{"g": 1, "x": 2}], by="g", aggs={"g": ("x", "sum")}, as_records=True)
+        assert False
+    except ValueError:
+        pass
+    result = rustdash.aggregate([{"g": 1, "x": 2}], by="g", aggs={"g": ("x", "sum")})
+    assert result == {1: {"g": 2}}
+
+
+# ==================== join Tests ====================
+
+USERS_DB = [
+    {"id": 1, "name": "ann"},
+    {"id": 2, "name": "bob"},
+    {"id": 3, "name": "cy"},
+]
+ORDERS_API = [
+    {"id": 10, "user_id": 2, "total": 5},
+    {"id": 11, "user_id": 1, "total": 7},
+    {"id": 12, "user_id": 2, "total": 9},
+    {"id": 13, "user_id": 4, "total": 1},
+]
+
+def test_join_inner_same_key():
+    left = [{"id": 1, "a": "x"}, {"id": 2, "a": "y"}]
+    right = [{"id": 2, "b": "z"}, {"id": 1, "b": "w"}]
+    assert rustdash.join(left, right, on="id") == [
+        {"id": 1, "a": "x", "b": "w"},
+        {"id": 2, "a": "y", "b": "z"},
+    ]
+
+def test_join_one_to_many_and_suffixes():
+    result = rustdash.join(USERS_DB, ORDERS_API, on=("id", "user_id"))
+    assert result == [
+        {"id": 1, "name": "ann", "id_right": 11, "user_id": 1, "total": 7},
+        {"id": 2, "name": "bob", "id_right": 10, "user_id": 2, "total": 5},
+        {"id": 2, "name": "bob", "id_right": 12, "user_id": 2, "total": 9},
+    ]
+
+def test_join_custom_suffixes():
+    result = rustdash.join(USERS_DB[:1], ORDERS_API, on=("id", "user_id"), suffixes=("_user", "_order"))
+    assert result == [{"id_user": 1, "name": "ann", "id_order": 11, "user_id": 1, "total": 7}]
+
+def test_join_left():
+    result = rustdash.join(USERS_DB, ORDERS_API, on=("id", "user_id"), how="left")
+    assert [(r["name"], r.get("total")) for r in result] == [("ann", 7), ("bob", 5), ("bob", 9), ("cy", None)]
+    assert result[-1] == {"id": 3, "name": "cy"}
+
+def test_join_right():
+    result = rustdash.join(USERS_DB, ORDERS_API, on=("id", "user_id"), how="right")
+    assert [(r.get("name"), r["total"]) for r in result] == [("bob", 5), ("ann", 7), ("bob", 9), (None, 1)]
+
+def test_join_outer():
+    left = [{"k": 1, "l": 1}, {"k": 2, "l": 2}]
+    right = [{"k": 2, "r": 2}, {"k": 3, "r": 3}]
+    assert rustdash.join(left, right, on="k", how="outer") == [
+        {"k": 1, "l": 1},
+        {"k": 2, "l": 2, "r": 2},
+        {"k": 3, "r": 3},
+    ]
+
+def test_join_unmatched_rows_keep_suffixes():
+    left = [{"k": 1, "v": "x"}, {"k": 2, "v": "y"}]
+    right = [{"k": 2, "v": "w"}, {"k": 3, "v": "z"}]
+    assert rustdash.join(left, right, on="k", how="outer") == [
+        {"k": 1, "v": "x"},
+        {"k": 2, "v": "y", "v_right": "w"},
+        {"k": 3, "v_right": "z"},
+    ]
+    result = rustdash.join(USERS_DB, ORDERS_API, on=("id", "user_id"), how="right")
+    assert result[-1] == {"id_right": 13, "user_id": 4, "total": 1}
+
+def test_join_suffix_clash_raises():
+    left = [{"k": 1, "v": "x", "v_right": "y"}]
+    right = [{"k": 1, "v": "w"}]
+    try:
+        rustdash.join(left, right, on="k")
+        assert False
+    except ValueError:
+        pass
+    result = rustdash.join(left, right, on="k", suffixes=("_l", "_r"))
+    assert result == [{"k": 1, "v_l": "x", "v_right": "y", "v_r": "w"}]
+
+def test_join_null_keys_never_match():
+    left = [{"k": None, "l": 1}, {"l": 2}]
+    right = [{"k": None, "r": 1}]
+    assert rustdash.join(left, right, on="k") == []
+    assert rustdash.join(left, right, on="k", how="outer") == [{"k": None, "l": 1}, {"l": 2}, {"k": None, "r": 1}]
+
+def test_join_nested_path_and_callable():
+    left = [{"user": {"id": 1}, "x": 1}]
+    right = [{"uid": "1", "y": 2}]
+    result = rustdash.join(left, right, on=("user.id", lambda r: int(r["uid"])))
+    assert result == [{"user": {"id": 1}, "x": 1, "uid": "1", "y": 2}]
+
+def test_join_nested_path_same_on_both_sides_suffixes_parent():
+    left = [{"user": {"id": 1}, "x": 1}]
+    right = [{"user": {"id": 1, "role": "admin"}}]
+    result = rustdash.join(left, right, on="user.id")
+    assert result == [{"user": {"id": 1}, "x": 1, "user_right": {"id": 1, "role": "admin"}}]
+
+def test_join_string_keys():
+    left = [{"code": "a", "v": 1}, {"code": "b", "v": 2}]
+    right = [{"code": "b", "w": 3}]
+    assert rustdash.join(left, right, on="code") == [{"code": "b", "v": 2, "w": 3}]
+
+def test_join_does_not_mutate_inputs():
+    left = [{"id": 1}]
+    right = [{"id": 1, "v": 2}]
+    result = rustdash.join(left, right, on="id", how="left")
+    result[0]["extra"] = True
+    assert left == [{"id": 1}]
+
+def test_join_invalid_arguments():
+    for kwargs, exc in (
+        ({"how": "cross"}, ValueError),
+        ({"suffixes": ("_x", "_x")}, ValueError),
+        ({"on": 5}, TypeError),
+    ):
+        try:
+            rustdash.join(USERS_DB, USERS_DB, **{"on": "id", **kwargs})
+            assert False
+        except exc:
+            pass
+    try:
+        rustdash.join([1], [], on="id")
+        assert False
+    except TypeError:
+        pass
 
 # ==================== map Tests ====================
 
@@ -122,6 +638,15 @@ def test_map_square():
 def test_map_negate():
     assert rustdash.map([1, -2, 3], lambda x: -x) == [-1, 2, -3]
 
+def test_map_path():
+    assert rustdash.map(USERS, "profile.age") == [34, 27, 41]
+
+def test_map_missing_path_is_none():
+    assert rustdash.map([{"a": 1}, {}], "a") == [1, None]
+
+def test_map_matches_property():
+    assert rustdash.map(USERS, ("profile.role", "user")) == [False, True, True]
+
 
 # ==================== filter Tests ====================
 
@@ -145,6 +670,54 @@ def test_filter_strings():
         ["hello", "hi", "hey", "world"], lambda s: s.startswith("h")
     ) == ["hello", "hi", "hey"]
 
+def test_filter_matches():
+    assert [u["name"] for u in rustdash.filter(USERS, {"active": True})] == ["ann", "cy"]
+
+def test_filter_matches_nested_partial():
+    result = rustdash.filter(USERS, {"profile": {"role": "user"}})
+    assert [u["name"] for u in result] == ["bob", "cy"]
+
+def test_filter_matches_list_subset():
+    result = rustdash.filter(USERS, {"profile": {"tags": ["b"]}})
+    assert [u["name"] for u in result] == ["ann", "bob"]
+
+def test_filter_matches_property():
+    assert [u["name"] for u in rustdash.filter(USERS, ["profile.age", 27])] == ["bob"]
+    assert [u["name"] for u in rustdash.filter(USERS, ("active", False))] == ["bob"]
+
+def test_list_of_two_strings_is_not_matches_property():
+    for fn in (rustdash.sort_by, rustdash.filter, rustdash.map):
+        try:
+            fn(USERS, ["name", "active"])
+            assert False
+        except TypeError as e:
+            assert "order_by" in str(e)
+
+def test_filter_matches_propagates_getitem_errors():
+    class Broken(dict):
+        def __getitem__(self, key):
+            raise RuntimeError("boom")
+
+    try:
+        rustdash.filter([Broken(a=1)], {"a": 1})
+        assert False
+    except RuntimeError:
+        pass
+    assert rustdash.filter([{"b": 1}, [1], 5], {"a": 1}) == []
+
+def test_filter_path_truthiness():
+    assert rustdash.filter([{"x": 0}, {"x": 2}, {}], "x") == [{"x": 2}]
+
+def test_filter_truthy_callable_result():
+    assert rustdash.filter([0, 1, 2], lambda x: x) == [1, 2]
+
+def test_filter_invalid_iteratee():
+    try:
+        rustdash.filter([1], 5)
+        assert False
+    except TypeError:
+        pass
+
 
 # ==================== find Tests ====================
 
@@ -163,6 +736,13 @@ def test_find_empty():
 def test_find_string():
     assert rustdash.find(["hello", "world", "hey"], lambda s: s.startswith("w")) == "world"
 
+def test_find_matches():
+    assert rustdash.find(USERS, {"profile": {"role": "admin"}})["name"] == "ann"
+
+def test_find_matches_property():
+    assert rustdash.find(USERS, ("profile.role", "user"))["name"] == "bob"
+    assert rustdash.find(USERS, ("profile.role", "owner")) is None
+
 
 # ==================== sort_by Tests ====================
 
@@ -193,6 +773,102 @@ def test_sort_by_dict_field():
     assert result[1]["name"] == "Bob"
     assert result[2]["name"] == "Charlie"
 
+def test_sort_by_path():
+    assert [u["name"] for u in rustdash.sort_by(USERS, "profile.age")] == ["bob", "ann", "cy"]
+
+
+def test_sort_by_none_keys_last():
+    assert rustdash.sort_by([{"a": 2}, {}, {"a": 1}], "a") == [{"a": 1}, {"a": 2}, {}]
+
+def test_sort_by_nan_keys_last():
+    result = rustdash.sort_by([2.0, float("nan"), 1.0], lambda x: x)
+    assert result[:2] == [1.0, 2.0] and result[2] != result[2]
+
+def test_sort_by_incomparable_raises():
+    try:
+        rustdash.sort_by([1, "a", 2], lambda x: x)
+        assert False
+    except TypeError:
+        pass
+
+def test_sort_by_is_stable():
+    words = ["bb", "a", "cc", "d", "ee"]
+    assert rustdash.sort_by(words, len) == ["a", "d", "bb", "cc", "ee"]
+
+
+# ==================== order_by Tests ====================
+
+ROWS = [
+    {"name": "fred", "age": 48},
+    {"name": "barney", "age": 34},
+    {"name": "fred", "age": 40},
+    {"name": "barney", "age": 36},
+]
+
+def test_order_by_multiple_keys():
+    result = rustdash.order_by(ROWS, ["name", "age"], ["asc", "desc"])
+    assert [(r["name"], r["age"]) for r in result] == [
+        ("barney", 36), ("barney", 34), ("fred", 48), ("fred", 40)
+    ]
+
+def test_order_by_default_ascending():
+    result = rustdash.order_by(ROWS, ["name", "age"])
+    assert [r["age"] for r in result] == [34, 36, 40, 48]
+
+def test_order_by_missing_orders_are_ascending():
+    result = rustdash.order_by(ROWS, ["name", "age"], ["desc"])
+    assert [r["age"] for r in result] == [40, 48, 34, 36]
+
+def test_order_by_single_key_and_order():
+    assert rustdash.order_by([2, 3, 1], lambda x: x, "desc") == [3, 2, 1]
+
+def test_order_by_callables_and_paths():
+    result = rustdash.order_by(USERS, [lambda u: u["active"], "profile.age"], ["desc", "asc"])
+    assert [u["name"] for u in result] == ["ann", "cy", "bob"]
+
+def test_order_by_nulls():
+    rows = [{"v": 2}, {"v": None}, {"v": 1}, {}]
+    last = rustdash.order_by(rows, "v", "desc")
+    assert last == [{"v": 2}, {"v": 1}, {"v": None}, {}]
+    first = rustdash.order_by(rows, "v", "desc", nulls="first")
+    assert first == [{"v": None}, {}, {"v": 2}, {"v": 1}]
+
+def test_order_by_nan_is_null():
+    nan = float("nan")
+    result = rustdash.order_by([2.0, nan, 1.0], lambda x: x)
+    assert result[:2] == [1.0, 2.0]
+
+def test_order_by_mixed_numbers_and_objects():
+    from decimal import Decimal
+    assert rustdash.order_by([2, 1.5, Decimal("1.7"), True], lambda x: x) == [True, 1.5, Decimal("1.7"), 2]
+
+def test_order_by_stable():
+    rows = [{"k": 1, "i": i} for i in range(5)] + [{"k": 0, "i": 5}]
+    result = rustdash.order_by(rows, "k", "desc")
+    assert [r["i"] for r in result] == [0, 1, 2, 3, 4, 5]
+
+def test_order_by_incomparable_raises():
+    try:
+        rustdash.order_by([{"v": 1}, {"v": "x"}], "v")
+        assert False
+    except TypeError:
+        pass
+
+def test_order_by_no_keys_keeps_items():
+    assert rustdash.order_by([3, 1, 2], []) == [3, 1, 2]
+
+def test_order_by_invalid_options():
+    for kwargs in (
+        {"orders": "up"},
+        {"orders": ["asc", "sideways"]},
+        {"orders": ["asc", "desc"]},
+        {"nulls": "middle"},
+    ):
+        try:
+            rustdash.order_by([1, 2], lambda x: x, **kwargs)
+            assert False
+        except ValueError:
+            pass
 
 # ==================== reduce Tests ====================
 
@@ -241,6 +917,95 @@ def test_zip_strings():
     ]
 
 
+def test_zip_variadic():
+    assert rustdash.zip([1, 2], ["a", "b"], [True, False]) == [[1, "a", True], [2, "b", False]]
+
+def test_zip_tuples_and_iterables():
+    assert rustdash.zip((1, 2), range(3), iter("xy")) == [[1, 0, "x"], [2, 1, "y"]]
+
+def test_zip_as_tuples():
+    assert rustdash.zip([1, 2], [3, 4], as_tuples=True) == [(1, 3), (2, 4)]
+
+def test_zip_no_lists():
+    assert rustdash.zip() == []
+
+
+# ==================== zip_longest Tests ====================
+
+def test_zip_longest_pads_with_none():
+    assert rustdash.zip_longest([1, 2, 3], ["a"]) == [[1, "a"], [2, None], [3, None]]
+
+def test_zip_longest_fill_and_tuples():
+    assert rustdash.zip_longest([1], [2, 3], fill=0, as_tuples=True) == [(1, 2), (0, 3)]
+
+
+# ==================== unzip Tests ====================
+
+def test_unzip_inverts_zip():
+    pairs = rustdash.zip([1, 2, 3], ["a", "b", "c"])
+    assert rustdash.unzip(pairs) == [[1, 2, 3], ["a", "b", "c"]]
+
+def test_unzip_tuples():
+    assert rustdash.unzip([(1, "a", True), (2, "b", False)], as_tuples=True) == [
+        (1, 2), ("a", "b"), (True, False)
+    ]
+
+def test_unzip_ragged_truncates():
+    assert rustdash.unzip([[1, 2, 3], [4, 5]]) == [[1, 4], [2, 5]]
+
+def test_unzip_empty():
+    assert rustdash.unzip([]) == []
+
+
+# ==================== zip_with Tests ====================
+
+def test_zip_with():
+    assert rustdash.zip_with(lambda a, b, c: a + b + c, [1, 2], [10, 20], [100, 200, 300]) == [111, 222]
+
+def test_zip_with_single_list():
+    assert rustdash.zip_with(str, [1, 2]) == ["1", "2"]
+
+
+# ==================== zip_object Tests ====================
+
+def test_zip_object():
+    assert rustdash.zip_object(["a", "b"], [1, 2]) == {"a": 1, "b": 2}
+
+def test_zip_object_missing_and_extra_values():
+    assert rustdash.zip_object(["a", "b"], [1]) == {"a": 1, "b": None}
+    assert rustdash.zip_object(["a"], [1, 2]) == {"a": 1}
+
+def test_zip_object_iterables():
+    assert rustdash.zip_object(("x", 2), iter([True, False])) == {"x": True, 2: False}
+
+
+# ==================== zip_object_deep Tests ====================
+
+def test_zip_object_deep():
+    assert rustdash.zip_object_deep(["a.b[0].c", "a.b[1].d"], [1, 2]) == {"a": {"b": [{"c": 1}, {"d": 2}]}}
+
+def test_zip_object_deep_pads_lists_and_merges_dicts():
+    result = rustdash.zip_object_deep(["x[2]", "y.a", "y.b"], [1, 2, 3])
+    assert result == {"x": [None, None, 1], "y": {"a": 2, "b": 3}}
+
+def test_zip_object_deep_huge_index_raises():
+    try:
+        rustdash.zip_object_deep(["a[100000000]"], [1])
+        assert False
+    except ValueError:
+        pass
+    assert len(rustdash.zip_object_deep(["a[100000]"], [1])["a"]) == 100001
+
+def test_zip_object_deep_replaces_scalars_in_the_way():
+    assert rustdash.zip_object_deep(["a", "a.b"], [1, 2]) == {"a": {"b": 2}}
+
+def test_zip_object_deep_wildcard_raises():
+    try:
+        rustdash.zip_object_deep(["a[*].b"], [1])
+        assert False
+    except ValueError:
+        pass
+
 # ==================== intersection Tests ====================
 
 def test_intersection_basic():
@@ -260,3 +1025,93 @@ def test_intersection_empty():
 
 def test_intersection_strings():
     assert rustdash.intersection(["a", "b", "c"], ["b", "c", "d"]) == ["b", "c"]
+
+def test_intersection_variadic():
+    assert rustdash.intersection([1, 2, 3, 4], [4, 3, 2], [3, 4, 5]) == [3, 4]
+
+def test_intersection_preserves_first_list_order():
+    assert rustdash.intersection([3, 1, 2], [2, 3]) == [3, 2]
+
+def test_intersection_floats():
+    assert rustdash.intersection([1.5, 0.0, 2.5], [-0.0, 2.5]) == [0.0, 2.5]
+
+def test_intersection_mixed_types():
+    assert rustdash.intersection([1, "a", (1, 2), None], [None, (1, 2), 1.0]) == [1, (1, 2), None]
+
+def test_intersection_large_ints():
+    assert rustdash.intersection([2**70, 1], [2**70]) == [2**70]
+
+def test_intersection_single_and_none():
+    assert rustdash.intersection([1, 1, 2]) == [1, 2]
+    assert rustdash.intersection() == []
+
+def test_intersection_by():
+    assert rustdash.intersection_by([2.1, 1.2], [2.3, 3.4], int) == [2.1]
+    assert rustdash.intersection_by([{"x": 1}, {"x": 2}], [{"x": 1}], "x") == [{"x": 1}]
+
+def test_intersection_with():
+    close = lambda a, b: abs(a - b) < 0.5
+    assert rustdash.intersection_with([1.0, 2.0, 3.0], [2.2, 2.9], close) == [2.0, 3.0]
+
+def test_intersection_unhashable():
+    assert rustdash.intersection([[1], {"a": [2]}], [{"a": [2]}]) == [{"a": [2]}]
+
+
+# ==================== difference Tests ====================
+
+def test_difference_basic():
+    assert rustdash.difference([2, 1], [2, 3]) == [1]
+
+def test_difference_variadic_keeps_duplicates():
+    assert rustdash.difference([1, 2, 1, 3, 4], [2], [4]) == [1, 1, 3]
+
+def test_difference_strings():
+    assert rustdash.difference(["a", "b", "c"], ["b"]) == ["a", "c"]
+
+def test_difference_by():
+    assert rustdash.difference_by([2.1, 1.2], [2.3, 3.4], int) == [1.2]
+    assert rustdash.difference_by([{"x": 2}, {"x": 1}], [{"x": 1}], "x") == [{"x": 2}]
+
+def test_difference_with():
+    same_id = lambda a, b: a["id"] == b["id"]
+    assert rustdash.difference_with([{"id": 1}, {"id": 2}], [{"id": 2, "v": 0}], same_id) == [{"id": 1}]
+
+def test_difference_by_requires_iteratee():
+    try:
+        rustdash.difference_by()
+        assert False
+    except TypeError:
+        pass
+
+
+# ==================== union Tests ====================
+
+def test_union_basic():
+    assert rustdash.union([2], [1, 2]) == [2, 1]
+
+def test_union_variadic():
+    assert rustdash.union([1, 1], [2, 1], [3, "a"], ["a"]) == [1, 2, 3, "a"]
+
+def test_union_by():
+    assert rustdash.union_by([2.1], [1.2, 2.3], int) == [2.1, 1.2]
+
+def test_union_with():
+    same_lower = lambda a, b: a.lower() == b.lower()
+    assert rustdash.union_with(["A", "b"], ["a", "B", "c"], same_lower) == ["A", "b", "c"]
+
+
+# ==================== xor Tests ====================
+
+def test_xor_basic():
+    assert rustdash.xor([2, 1], [2, 3]) == [1, 3]
+
+def test_xor_variadic():
+    assert rustdash.xor([1, 2], [2, 3], [3, 4]) == [1, 4]
+
+def test_xor_by():
+    assert rustdash.xor_by([2.1, 1.2], [2.3, 3.4], int) == [1.2, 3.4]
+    assert rustdash.xor_by([{"x": 1}], [{"x": 2}, {"x": 1}], "x") == [{"x": 2}]
+
+def test_xor_with():
+    same_lower = lambda a, b: a.lower() == b.lower()
+    assert rustdash.xor_with(["A", "b"], ["a", "C"], same_lower) == ["b", "C"]
@@ -1,4 +1,10 @@
+import array
 import math
+import pickle
+import struct
+from datetime import date
+from decimal import Decimal
+
 import rustdash
 
 
@@ -130,7 +136,7 @@ def test_mean_single_element():
     assert abs(rustdash.mean([5.0]) - 5.0) < 1e-10
 
 def test_mean_empty():
-    assert math.isnan(rustdash.mean([]))
+    assert rustdash.mean([]) is None
 
 def test_mean_negative_numbers():
     assert abs(rustdash.mean([-1.0, -2.0, -3.0]) - (-2.0)) < 1e-10
@@ -199,3 +205,873 @@ def test_round_array_single_element():
     result = rustdash.round([3.14159], 3)
     assert len(result) == 1
     assert abs(result[0] - 3.142) < 1e-10
+
+
+# ==================== buffer protocol Tests ====================
+
+def test_sum_array_ints():
+    assert rustdash.sum(array.array("q", [1, 2, 3])) == 6
+
+def test_sum_array_floats():
+    assert abs(rustdash.sum(array.array("d", [0.5, 1.5, 2.0])) - 4.0) < 1e-10
+
+def test_sum_memoryview():
+    assert rustdash.sum(memoryview(array.array("q", [10, 20, 30]))) == 60
+
+def test_sum_bytes_of_doubles():
+    data = struct.pack("=3d", 1.0, 2.0, 3.5)
+    assert abs(rustdash.sum(data) - 6.5) < 1e-10
+
+def test_sum_bytearray_of_doubles():
+    data = bytearray(struct.pack("=2d", 1.5, 2.0))
+    assert abs(rustdash.sum(data) - 3.5) < 1e-10
+
+def test_sum_byte_memoryview_of_doubles():
+    data = struct.pack("=2d", 1.5, 2.0)
+    assert abs(rustdash.sum(memoryview(data)) - 3.5) < 1e-10
+    assert abs(rustdash.sum(memoryview(bytearray(data))) - 3.5) < 1e-10
+
+def test_sum_bytes_bad_length():
+    for data in (b"\x01\x02", bytearray(b"abc"), memoryview(b"\x01\x02")):
+        try:
+            rustdash.sum(data)
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+
+def test_sum_writable_buffer_is_read_in_place():
+    values = array.array("d", [1.0, 2.0])
+    assert rustdash.sum(values) == 3.0
+    values[0] = 10.0
+    assert rustdash.sum(values) == 12.0
+
+def test_sum_non_contiguous_memoryview():
+    view = memoryview(array.array("d", [1.0, 2.0, 3.0, 4.0]))[::2]
+    assert abs(rustdash.sum(view) - 4.0) < 1e-10
+
+def test_mean_array_ints():
+    assert abs(rustdash.mean(array.array("q", [1, 2, 3, 4])) - 2.5) < 1e-10
+
+def test_min_max_array():
+    values = array.array("d", [3.5, -1.0, 2.0])
+    assert rustdash.min(values) == -1.0
+    assert rustdash.max(values) == 3.5
+
+def test_max_array_ints_returns_int():
+    result = rustdash.max(array.array("q", [3, 9, 4]))
+    assert result == 9
+    assert isinstance(result, int)
+
+def test_round_array_buffer():
+    assert rustdash.round(array.array("d", [1.234, 5.678]), 1) == [1.2, 5.7]
+
+def test_buffer_empty():
+    assert rustdash.sum(array.array("d")) == 0.0
+    assert rustdash.max(array.array("q")) is None
+
+
+# ==================== cumulative Tests ====================
+
+def test_cumsum_ints():
+    result = rustdash.cumsum([1, 2, 3, 4])
+    assert result == [1, 3, 6, 10]
+    assert all(isinstance(x, int) for x in result)
+
+def test_cumsum_floats():
+    assert rustdash.cumsum([0.5, 0.5, 1.0]) == [0.5, 1.0, 2.0]
+
+def test_cumprod():
+    assert rustdash.cumprod([1, 2, 3, 4]) == [1, 2, 6, 24]
+
+def test_cummax_cummin():
+    assert rustdash.cummax([1, 3, 2, 5]) == [1, 3, 3, 5]
+    assert rustdash.cummin([5, 3, 4, 1]) == [5, 3, 3, 1]
+
+def test_cumsum_nan_omit():
+    result = rustdash.cumsum([1.0, float("nan"), 2.0], nan_policy="omit")
+    assert result[0] == 1.0
+    assert math.isnan(result[1])
+    assert result[2] == 3.0
+
+def test_cumsum_nan_raise():
+    try:
+        rustdash.cumsum([1.0, float("nan")], nan_policy="raise")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_cumsum_bad_policy():
+    try:
+        rustdash.cumsum([1.0], nan_policy="ignore")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_cumulative_int_overflow_uses_python_ints():
+    assert rustdash.cumsum([2**62, 2**62]) == [2**62, 2**63]
+    assert rustdash.cumprod([10] * 20)[-1] == 10**20
+    assert rustdash.diff([-2**63, 2**62]) == [2**62 + 2**63]
+
+def test_diff():
+    assert rustdash.diff([1, 4, 9, 16]) == [3, 5, 7]
+    assert rustdash.diff([1, 4, 9, 16], lag=2) == [8, 12]
+
+def test_pct_change():
+    result = rustdash.pct_change([100, 110, 99])
+    assert abs(result[0] - 0.1) < 1e-10
+    assert abs(result[1] + 0.1) < 1e-10
+
+def test_diff_pct_change_omit_drops_nan():
+    nan = float("nan")
+    assert rustdash.diff([1.0, nan, 4.0, 9.0], nan_policy="omit") == [3.0, 5.0]
+    assert rustdash.pct_change([1.0, nan, 2.0], nan_policy="omit") == [1.0]
+
+def test_ewm_mean():
+    assert rustdash.ewm_mean([1.0, 2.0, 3.0], 0.5) == [1.0, 1.5, 2.25]
+
+
+# ==================== rolling Tests ====================
+
+def test_rolling_default_mean():
+    assert rustdash.rolling([1, 2, 3, 4, 5], 3) == [2.0, 3.0, 4.0]
+
+def test_rolling_stats():
+    values = [4, 2, 12, 3, 1, 7]
+    assert rustdash.rolling(values, 3, stat="min") == [2.0, 2.0, 1.0, 1.0]
+    assert rustdash.rolling(values, 3, stat="max") == [12.0, 12.0, 12.0, 7.0]
+    assert rustdash.rolling(values, 2, stat="sum") == [6.0, 14.0, 15.0, 4.0, 8.0]
+
+def test_rolling_std():
+    result = rustdash.rolling([2.0, 4.0, 4.0, 4.0, 5.0], 3, stat="std")
+    assert abs(result[0] - 1.1547005383792515) < 1e-10
+
+def test_rolling_infinity_and_precision():
+    assert rustdash.rolling([math.inf, 1, 2, 3, 4], 2, "sum") == [math.inf, 3.0, 5.0, 7.0]
+    assert rustdash.rolling([1e16, 1, 2, 3], 2, "sum") == [1e16, 3.0, 5.0]
+    assert rustdash.rolling([1e9 + 1, 1e9 + 2, 1e9 + 3, 1e9 + 4], 3, "std") == [1.0, 1.0]
+
+def test_rolling_array_buffer():
+    assert rustdash.rolling(array.array("d", [1.0, 3.0, 5.0]), 2) == [2.0, 4.0]
+
+def test_rolling_invalid_window():
+    try:
+        rustdash.rolling([1, 2, 3], 0)
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_rolling_invalid_stat():
+    try:
+        rustdash.rolling([1, 2, 3], 2, stat="median")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+
+# ==================== nan_policy / skip_none Tests ====================
+
+NAN = float("nan")
+
+def test_max_nan_propagates_regardless_of_position():
+    assert math.isnan(rustdash.max([NAN, 1.0, 2.0]))
+    assert math.isnan(rustdash.max([1.0, 2.0, NAN]))
+
+def test_nan_policy_omit():
+    assert rustdash.sum([1.0, NAN, 2.0], nan_policy="omit") == 3.0
+    assert rustdash.mean([1.0, NAN, 3.0], nan_policy="omit") == 2.0
+    assert rustdash.min([NAN, 4.0, 2.0], nan_policy="omit") == 2.0
+    assert rustdash.max([NAN, 4.0, 2.0], nan_policy="omit") == 4.0
+    rounded = rustdash.round([1.26, NAN], 1, nan_policy="omit")
+    assert rounded[0] == 1.3 and math.isnan(rounded[1])
+
+def test_nan_policy_omit_all_nan():
+    assert rustdash.mean([NAN, NAN], nan_policy="omit") is None
+    assert rustdash.max([NAN], nan_policy="omit") is None
+
+def test_nan_policy_raise():
+    for fn in (rustdash.sum, rustdash.mean, rustdash.min, rustdash.max):
+        try:
+            fn([1.0, NAN], nan_policy="raise")
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+
+def test_round_scalar_nan_raise():
+    try:
+        rustdash.round(NAN, 2, nan_policy="raise")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_round_keeps_positions():
+    assert rustdash.round([1.25, None, 2.5], 1, skip_none=True) == [1.3, None, 2.5]
+    result = rustdash.round([1.25, NAN, 2.5], 1, nan_policy="omit")
+    assert len(result) == 3 and math.isnan(result[1])
+
+def test_none_raises_with_index():
+    try:
+        rustdash.sum([1, None, 3])
+        assert False, "expected TypeError"
+    except TypeError as e:
+        assert "index 1" in str(e)
+
+def test_skip_none():
+    assert rustdash.sum([1, None, 3], skip_none=True) == 4
+    assert rustdash.mean([None, 2.0, 4.0], skip_none=True) == 3.0
+    assert rustdash.min([None, 5, 2], skip_none=True) == 2
+    assert rustdash.max([None, None], skip_none=True) is None
+    assert rustdash.round([1.24, None], 1, skip_none=True) == [1.2, None]
+
+def test_by_skip_none():
+    items = [{"v": 1}, {"v": None}, {"v": 3}]
+    assert rustdash.sum_by(items, lambda x: x["v"], skip_none=True) == 4.0
+    assert rustdash.max_by(items, lambda x: x["v"], skip_none=True) == {"v": 3}
+    assert rustdash.min_by(items, lambda x: x["v"], skip_none=True) == {"v": 1}
+
+def test_by_none_raises():
+    try:
+        rustdash.sum_by([{"v": None}], lambda x: x["v"])
+        assert False, "expected TypeError"
+    except TypeError:
+        pass
+
+def test_by_nan_policies():
+    items = [{"v": 1.0}, {"v": NAN}, {"v": 3.0}]
+    assert rustdash.max_by(items, lambda x: x["v"], nan_policy="omit") == {"v": 3.0}
+    assert rustdash.max_by(items, lambda x: x["v"]) is items[1]
+    assert rustdash.sum_by(items, lambda x: x["v"], nan_policy="omit") == 4.0
+    assert math.isnan(rustdash.sum_by(items, lambda x: x["v"]))
+    try:
+        rustdash.min_by(items, lambda x: x["v"], nan_policy="raise")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+
+# ==================== mean_by / weighted / geometric / harmonic Tests ====================
+
+def test_mean_by_basic():
+    items = [{"price": 10}, {"price": 20}, {"price": 60}]
+    assert rustdash.mean_by(items, lambda x: x["price"]) == 30.0
+
+def test_mean_by_empty():
+    assert rustdash.mean_by([], lambda x: x) is None
+
+def test_mean_by_skip_none():
+    items = [{"price": 10}, {"price": None}]
+    assert rustdash.mean_by(items, lambda x: x["price"], skip_none=True) == 10.0
+
+def test_weighted_mean():
+    assert rustdash.weighted_mean([1.0, 2.0, 3.0], [3, 1, 0]) == 1.25
+
+def test_weighted_mean_buffers():
+    values = array.array("d", [10.0, 20.0])
+    weights = array.array("d", [1.0, 3.0])
+    assert rustdash.weighted_mean(values, weights) == 17.5
+
+def test_weighted_mean_errors():
+    for values, weights in (([1, 2], [1]), ([1, 2], [0, 0]), ([1, 2], [1, -1])):
+        try:
+            rustdash.weighted_mean(values, weights)
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+
+def test_geometric_mean():
+    assert abs(rustdash.geometric_mean([1, 4, 16]) - 4.0) < 1e-10
+    assert rustdash.geometric_mean([]) is None
+
+def test_harmonic_mean():
+    assert abs(rustdash.harmonic_mean([40, 60]) - 48.0) < 1e-10
+
+def test_geometric_harmonic_non_positive():
+    for fn in (rustdash.geometric_mean, rustdash.harmonic_mean):
+        try:
+            fn([1, 0, 2])
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+
+
+# ==================== covariance / correlation / regression Tests ====================
+
+def test_covariance():
+    assert abs(rustdash.covariance([1, 2, 3, 4], [2, 4, 6, 8]) - 10 / 3) < 1e-10
+
+def test_correlation_methods():
+    xs = [1, 2, 3, 4, 5]
+    ys = [3, 1, 2, 5, 4]
+    assert abs(rustdash.correlation(xs, ys) - 0.6) < 1e-10
+    assert abs(rustdash.correlation(xs, ys, method="spearman") - 0.6) < 1e-10
+    assert abs(rustdash.correlation(xs, ys, method="kendall") - 0.4) < 1e-10
+
+def test_correlation_buffers():
+    xs = array.array("d", [1.0, 2.0, 3.0])
+    ys = array.array("q", [3, 2, 1])
+    assert abs(rustdash.correlation(xs, ys) + 1.0) < 1e-10
+
+def test_correlation_errors():
+    for args, kwargs in (
+        (([1, 2], [1]), {}),
+        (([1, 1, 1], [1, 2, 3]), {}),
+        (([1, 2], [1, 2]), {"method": "cosine"}),
+    ):
+        try:
+            rustdash.correlation(*args, **kwargs)
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+
+def test_linear_regression():
+    fit = rustdash.linear_regression([1, 2, 3, 4], [2, 3, 5, 4])
+    assert abs(fit["slope"] - 0.8) < 1e-10
+    assert abs(fit["intercept"] - 1.5) < 1e-10
+    assert abs(fit["r_squared"] - 0.64) < 1e-10
+
+def test_linear_regression_constant_xs():
+    try:
+        rustdash.linear_regression([2, 2], [1, 3])
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+
+# ==================== histogram / digitize / bin_by Tests ====================
+
+def test_histogram_default_bins():
+    counts, edges = rustdash.histogram(list(range(10)))
+    assert counts == [1] * 10
+    assert len(edges) == 11
+
+def test_histogram_count_and_range():
+    counts, edges = rustdash.histogram([0, 5, 10, 20], bins=2, range=(0, 10))
+    assert counts == [1, 2]
+    assert edges == [0.0, 5.0, 10.0]
+
+def test_histogram_edges():
+    counts, edges = rustdash.histogram([5, 10, 50, 100, 150], bins=[0, 10, 100])
+    assert counts == [1, 3]
+    assert edges == [0.0, 10.0, 100.0]
+
+def test_histogram_method():
+    counts, _ = rustdash.histogram(list(range(100)), bins="sturges")
+    assert len(counts) == 8
+
+def test_histogram_invalid_bins():
+    try:
+        rustdash.histogram([1, 2], bins="median")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_histogram_infinity_is_ignored():
+    counts, edges = rustdash.histogram([0.0, math.inf], bins=1)
+    assert counts == [1] and edges == [-0.5, 0.5]
+    counts, _ = rustdash.histogram([1.0, -math.inf], bins="auto")
+    assert counts == [1]
+
+def test_histogram_too_many_bins():
+    values = [0.0] * 10 + [0.001 * i for i in range(10)] + [1e9]
+    try:
+        rustdash.histogram(values, bins="fd")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+    assert rustdash.histogram(values, bins="auto") == rustdash.histogram(values, bins="sturges")
+
+def test_digitize():
+    assert rustdash.digitize([-5, 0, 5, 10, 25], [0, 10, 20]) == [0, 1, 1, 2, 3]
+
+def test_bin_by_path():
+    orders = [{"amount": 5}, {"amount": 50}, {"amount": 15}, {"amount": 500}]
+    result = rustdash.bin_by(orders, "amount", [0, 10, 100])
+    assert result == {
+        1: [{"amount": 5}],
+        2: [{"amount": 50}, {"amount": 15}],
+        3: [{"amount": 500}],
+    }
+
+def test_bin_by_callable():
+    result = rustdash.bin_by([1, 12, 7], lambda x: x, [0, 10])
+    assert result == {1: [1, 7], 2: [12]}
+
+def test_bin_by_missing_key():
+    result = rustdash.bin_by([{"amount": 5}, {}], "amount", [0, 10])
+    assert result == {1: [{"amount": 5}], None: [{}]}
+
+
+# ==================== RunningStats Tests ====================
+
+def test_running_stats_basic():
+    stats = rustdash.RunningStats()
+    for x in [2, 4, 4, 4, 5, 5, 7, 9]:
+        stats.push(x)
+    assert stats.count == 8
+    assert len(stats) == 8
+    assert abs(stats.mean - 5.0) < 1e-12
+    assert abs(stats.variance - 32 / 7) < 1e-12
+    assert abs(stats.std - math.sqrt(32 / 7)) < 1e-12
+    assert stats.min == 2.0
+    assert stats.max == 9.0
+
+def test_running_stats_empty():
+    stats = rustdash.RunningStats()
+    assert stats.count == 0
+    assert stats.mean is None
+    assert stats.variance is None
+    assert stats.quantile(0.5) is None
+
+def test_running_stats_extend_sources():
+    stats = rustdash.RunningStats()
+    stats.extend([1, 2])
+    stats.extend(array.array("d", [3.0]))
+    stats.extend(x for x in (4, 5))
+    assert stats.count == 5
+    assert stats.mean == 3.0
+
+def test_running_stats_merge():
+    left, right = rustdash.RunningStats(), rustdash.RunningStats()
+    left.extend([1.0, 2.0])
+    right.extend([3.0, 4.0])
+    left.merge(right)
+    assert left.count == 4
+    assert left.mean == 2.5
+    assert left.max == 4.0
+
+def test_running_stats_merge_self():
+    stats = rustdash.RunningStats()
+    stats.extend([1.0, 3.0])
+    stats.merge(stats)
+    assert stats.count == 4
+    assert stats.mean == 2.0
+
+def test_running_stats_compression_validated():
+    for compression in (0, -1, math.nan, math.inf):
+        try:
+            rustdash.RunningStats(compression=compression)
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+    state = bytearray(rustdash.RunningStats().__getstate__())
+    state[40:48] = struct.pack("<d", 0.0)
+    try:
+        rustdash.RunningStats().__setstate__(bytes(state))
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_running_stats_quantile():
+    stats = rustdash.RunningStats()
+    stats.extend(range(1, 10001))
+    assert abs(stats.quantile(0.5) - 5000) < 50
+    try:
+        stats.quantile(2.0)
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_running_stats_pickle():
+    stats = rustdash.RunningStats(compression=50)
+    stats.extend(range(1000))
+    restored = pickle.loads(pickle.dumps(stats))
+    assert isinstance(restored, rustdash.RunningStats)
+    assert restored.count == stats.count
+    assert restored.mean == stats.mean
+    assert restored.variance == stats.variance
+    assert restored.quantile(0.5) == stats.quantile(0.5)
+
+def test_running_stats_invalid_compression():
+    try:
+        rustdash.RunningStats(compression=0)
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+
+# ==================== clamp / in_range Tests ====================
+
+def test_clamp_scalar():
+    assert rustdash.clamp(-10, -5, 5) == -5
+    assert rustdash.clamp(10, -5, 5) == 5
+    assert rustdash.clamp(3, -5, 5) == 3
+    assert isinstance(rustdash.clamp(3, -5, 5), int)
+    assert rustdash.clamp(2.5, 0, 1) == 1.0
+
+def test_clamp_list():
+    assert rustdash.clamp([-3, 0, 7], 0, 5) == [0, 0, 5]
+    assert rustdash.clamp([0.5, 1.5], 0.0, 1.0) == [0.5, 1.0]
+    assert rustdash.clamp(array.array("q", [1, 9]), 2, 4) == [2, 4]
+
+def test_clamp_invalid_bounds():
+    try:
+        rustdash.clamp(1, 5, 0)
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_in_range():
+    assert rustdash.in_range(3, 2, 4) is True
+    assert rustdash.in_range(4, 8) is True
+    assert rustdash.in_range(4, 2) is False
+    assert rustdash.in_range(-3, -2, -6) is True
+    assert rustdash.in_range([1, 2, 3], 2, 3) == [False, True, False]
+
+
+# ==================== range / linspace / times Tests ====================
+
+def test_range_lodash_semantics():
+    assert rustdash.range(4) == [0, 1, 2, 3]
+    assert rustdash.range(-4) == [0, -1, -2, -3]
+    assert rustdash.range(1, 5) == [1, 2, 3, 4]
+    assert rustdash.range(0, 20, 5) == [0, 5, 10, 15]
+    assert rustdash.range(0) == []
+
+def test_range_float_step():
+    values = rustdash.range(0, 1, 0.1)
+    assert len(values) == 10
+    assert values[3] == 0.1 * 3
+    assert rustdash.range(0, 1, 0.25) == [0.0, 0.25, 0.5, 0.75]
+
+def test_range_zero_step():
+    try:
+        rustdash.range(0, 5, 0)
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_range_too_long_raises():
+    for make in (lambda: rustdash.range(-2**63, 2**63 - 1), lambda: rustdash.linspace(0, 1, 2**62)):
+        try:
+            make()
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+
+def test_range_right():
+    assert rustdash.range_right(4) == [3, 2, 1, 0]
+    assert rustdash.range_right(1, 5) == [4, 3, 2, 1]
+
+def test_linspace():
+    assert rustdash.linspace(0, 1, 5) == [0.0, 0.25, 0.5, 0.75, 1.0]
+    assert rustdash.linspace(0, 1, 4, endpoint=False) == [0.0, 0.25, 0.5, 0.75]
+    assert len(rustdash.linspace(0, 1)) == 50
+    assert rustdash.linspace(0, 1, 0) == []
+
+def test_times():
+    assert rustdash.times(3) == [0, 1, 2]
+    assert rustdash.times(3, lambda i: i * i) == [0, 1, 4]
+    assert rustdash.times(0, lambda i: i) == []
+
+
+# ==================== formatting Tests ====================
+
+def test_format_number():
+    assert rustdash.format_number(1234567.891, 2) == "1,234,567.89"
+    assert rustdash.format_number(1234567.891, 2, ".", ",") == "1.234.567,89"
+    assert rustdash.format_number(-1234, 1) == "-1,234.0"
+    assert rustdash.format_number(2**62) == "4,611,686,018,427,387,904"
+    assert rustdash.format_number(-0.001, 2) == "0.00"
+    assert rustdash.format_number([1000, 2000]) == ["1,000", "2,000"]
+
+def test_format_number_rounds_half_away_from_zero():
+    assert rustdash.format_number(2.5) == "3"
+    assert rustdash.format_number(-2.5) == "-3"
+
+def test_format_bytes():
+    assert rustdash.format_bytes(1572864) == "1.5 MiB"
+    assert rustdash.format_bytes(1572864, binary=False) == "1.6 MB"
+    assert rustdash.format_bytes(512) == "512 B"
+    assert rustdash.format_bytes(1024) == "1 KiB"
+    assert rustdash.format_bytes(1048575) == "1 MiB"
+    assert rustdash.format_bytes([0, 2048]) == ["0 B", "2 KiB"]
+
+def test_format_compact():
+    assert rustdash.format_compact(1234) == "1.2k"
+    assert rustdash.format_compact(2500000) == "2.5M"
+    assert rustdash.format_compact(999950) == "1M"
+    assert rustdash.format_compact(-1500) == "-1.5k"
+    assert rustdash.format_compact(12) == "12"
+
+def test_format_percent():
+    assert rustdash.format_percent(0.125, 1) == "12.5%"
+    assert rustdash.format_percent([0.5, 1]) == ["50%", "100%"]
+
+def test_format_duration():
+    assert rustdash.format_duration(3725) == "1h 2m 5s"
+    assert rustdash.format_duration(90061) == "1d 1h 1m 1s"
+    assert rustdash.format_duration(1.5) == "1.5s"
+    assert rustdash.format_duration(0.25) == "250ms"
+    assert rustdash.format_duration(59.97) == "1m"
+    assert rustdash.format_duration(0) == "0s"
+    assert rustdash.format_duration(-5) == "-5s"
+
+
+# ==================== parsing Tests ====================
+
+def test_parse_number_locales():
+    assert rustdash.parse_number("1,234.56") == 1234.56
+    assert rustdash.parse_number("1.234,56", locale="de") == 1234.56
+    assert rustdash.parse_number("1 234,56", locale="fr_FR") == 1234.56
+    assert rustdash.parse_number("1'234.5", locale="de-CH") == 1234.5
+    assert rustdash.parse_number(["1,000", "-2.5"]) == [1000.0, -2.5]
+
+def test_parse_number_invalid():
+    for bad in ["abc", "inf", ""]:
+        try:
+            rustdash.parse_number(bad)
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+    try:
+        rustdash.parse_number("1", locale="xx")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_parse_bytes():
+    assert rustdash.parse_bytes("10MB") == 10_000_000
+    assert rustdash.parse_bytes("1.5 GiB") == 1_610_612_736
+    assert rustdash.parse_bytes("512") == 512
+    assert rustdash.parse_bytes(["1k", "1KiB"]) == [1000, 1024]
+    try:
+        rustdash.parse_bytes("10 XB")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+
+# ==================== scaling Tests ====================
+
+def test_normalize():
+    assert rustdash.normalize([2, 4, 6]) == [0.0, 0.5, 1.0]
+    assert rustdash.normalize(array.array("d", [1.0, 3.0])) == [0.0, 1.0]
+    assert rustdash.normalize([]) == []
+
+def test_normalize_constant_input():
+    try:
+        rustdash.normalize([5, 5, 5])
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+    assert rustdash.normalize([5, 5, 5], fill=0.0) == [0.0, 0.0, 0.0]
+
+def test_normalize_keeps_nan():
+    result = rustdash.normalize([0.0, float("nan"), 10.0])
+    assert result[0] == 0.0 and result[2] == 1.0
+    assert math.isnan(result[1])
+
+def test_standardize():
+    result = rustdash.standardize([1, 2, 3])
+    assert result == [-1.0, 0.0, 1.0]
+    assert rustdash.standardize([7], fill=0.0) == [0.0]
+
+def test_robust_scale():
+    result = rustdash.robust_scale([1, 2, 3, 4, 5, 1000])
+    assert result[2] == -0.2
+    assert rustdash.robust_scale([1, 1, 1, 1, 9], fill=0.0) == [0.0] * 5
+
+def test_rescale():
+    assert rustdash.rescale([0, 5, 10], -1, 1) == [-1.0, 0.0, 1.0]
+    assert rustdash.rescale([3, 3], 10, 20, fill=15.0) == [15.0, 15.0]
+
+
+# ==================== argmin / argmax / min_max / top_k Tests ====================
+
+def test_argmax_argmin():
+    assert rustdash.argmax([3, 9, 2, 9]) == 1
+    assert rustdash.argmin([3, 1, 2, 1]) == 1
+    assert rustdash.argmax(array.array("d", [0.5, 2.5])) == 1
+    assert rustdash.argmax([]) is None
+    assert rustdash.argmin([1.0, float("nan"), 0.0]) == 1
+
+def test_argmax_by_argmin_by():
+    users = [{"age": 30}, {"age": 25}, {"age": 40}]
+    assert rustdash.argmax_by(users, "age") == 2
+    assert rustdash.argmin_by(users, lambda u: u["age"]) == 1
+    assert rustdash.argmin_by([], "age") is None
+
+def test_min_max():
+    assert rustdash.min_max([3, 1, 4, 1, 5]) == (1, 5)
+    assert rustdash.min_max([2.5]) == (2.5, 2.5)
+    assert rustdash.min_max([]) is None
+
+def test_min_max_by():
+    users = [{"name": "a", "age": 30}, {"name": "b", "age": 25}, {"name": "c", "age": 40}]
+    youngest, oldest = rustdash.min_max_by(users, "age")
+    assert youngest["name"] == "b"
+    assert oldest["name"] == "c"
+    assert rustdash.min_max_by([], "age") is None
+
+def test_top_k():
+    assert rustdash.top_k([5, 1, 9, 3, 7], 3) == [9, 7, 5]
+    assert rustdash.top_k([5, 1, 9, 3, 7], 2, largest=False) == [1, 3]
+    assert rustdash.top_k([1.0, float("nan"), 2.0], 5) == [2.0, 1.0]
+    assert rustdash.top_k([1, 2], 0) == []
+
+def test_top_k_key_ties_keep_order():
+    items = [{"id": 1, "score": 5}, {"id": 2, "score": 7}, {"id": 3, "score": 5}]
+    result = rustdash.top_k(items, 2, key="score")
+    assert [x["id"] for x in result] == [2, 1]
+    result = rustdash.top_k(items, 2, key=lambda x: x["score"], largest=False)
+    assert [x["id"] for x in result] == [1, 3]
+
+
+# ==================== _by with paths and rich comparison Tests ====================
+
+def test_by_functions_accept_paths():
+    users = [{"name": "a", "age": 30}, {"name": "b", "age": 25}, {"name": "c", "age": 40}]
+    assert rustdash.max_by(users, "age")["name"] == "c"
+    assert rustdash.min_by(users, "age")["name"] == "b"
+    assert rustdash.sum_by(users, "age") == 95
+    assert rustdash.mean_by(users, "age") == 95 / 3
+
+def test_by_functions_nested_path_and_missing_key():
+    rows = [{"stats": {"score": 3}}, {"stats": {}}, {"stats": {"score": 7}}]
+    assert rustdash.max_by(rows, "stats.score", skip_none=True)["stats"]["score"] == 7
+    try:
+        rustdash.sum_by(rows, "stats.score")
+        assert False, "expected TypeError"
+    except TypeError:
+        pass
+
+def test_max_by_decimal_keys():
+    prices = [{"p": Decimal("1.10")}, {"p": Decimal("1.30")}, {"p": Decimal("1.20")}]
+    assert rustdash.max_by(prices, "p")["p"] == Decimal("1.30")
+    assert rustdash.min_by(prices, lambda x: x["p"])["p"] == Decimal("1.10")
+
+def test_max_by_min_by_compare_large_ints_exactly():
+    rows = [{"ts": 1_700_000_000_000_000_000}, {"ts": 1_700_000_000_000_000_100}]
+    assert rustdash.max_by(rows, "ts") is rows[1]
+    assert rustdash.min_by(rows, "ts") is rows[0]
+    assert rustdash.max_by([2**53 + 1, 2.0**53], lambda x: x) == 2**53 + 1
+
+def test_arg_by_functions_compare_large_ints_exactly():
+    rows = [{"ts": 1_700_000_000_000_000_000}, {"ts": 1_700_000_000_000_000_100}]
+    assert rustdash.argmax_by(rows, "ts") == 1
+    assert rustdash.argmin_by(rows, "ts") == 0
+    assert rustdash.min_max_by(rows, "ts") == (rows[0], rows[1])
+    assert rustdash.top_k(rows, 1, key="ts") == [rows[1]]
+
+def test_min_by_date_and_string_keys():
+    events = [{"at": date(2024, 5, 1)}, {"at": date(2023, 1, 9)}, {"at": date(2024, 1, 2)}]
+    assert rustdash.min_by(events, "at")["at"] == date(2023, 1, 9)
+    assert rustdash.max_by(["pear", "apple", "zoo"], lambda s: s) == "zoo"
+
+def test_max_by_incomparable_keys_raise():
+    try:
+        rustdash.max_by([1, 2], lambda x: "a" if x == 1 else (1, 2))
+        assert False, "expected TypeError"
+    except TypeError:
+        pass
+
+def test_by_functions_reject_bad_iteratee():
+    try:
+        rustdash.max_by([1, 2], 5)
+        assert False, "expected TypeError"
+    except TypeError:
+        pass
+
+
+# ==================== integer math Tests ====================
+
+def test_gcd_lcm():
+    assert rustdash.gcd([12, 18, 24]) == 6
+    assert rustdash.lcm([4, 6, 10]) == 60
+    assert rustdash.gcd([]) == 0
+    assert rustdash.lcm(array.array("q", [3, 5])) == 15
+
+def test_gcd_lcm_big_ints():
+    assert rustdash.gcd([2**70, 2**65]) == 2**65
+    assert rustdash.lcm([2**40, 3**30]) == math.lcm(2**40, 3**30)
+
+def test_gcd_rejects_floats():
+    try:
+        rustdash.gcd([1.5, 2])
+        assert False, "expected TypeError"
+    except TypeError:
+        pass
+
+def test_factorial():
+    assert rustdash.factorial(5) == 120
+    assert rustdash.factorial(30) == math.factorial(30)
+    try:
+        rustdash.factorial(-1)
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_n_choose_k():
+    assert rustdash.n_choose_k(5, 2) == 10
+    assert rustdash.n_choose_k(3, 5) == 0
+    assert rustdash.n_choose_k(100, 50) == math.comb(100, 50)
+
+def test_mod_pow():
+    assert rustdash.mod_pow(3, 200, 13) == pow(3, 200, 13)
+    assert rustdash.mod_pow(3, -1, 7) == 5
+    assert rustdash.mod_pow(2**100, 5, 10**30 + 7) == pow(2**100, 5, 10**30 + 7)
+
+def test_is_prime_and_primes_up_to():
+    assert rustdash.is_prime(97) is True
+    assert rustdash.is_prime([1, 2, 9, 2**61 - 1]) == [False, True, False, True]
+    assert rustdash.primes_up_to(30) == [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+    assert rustdash.primes_up_to(-5) == []
+    try:
+        rustdash.primes_up_to(2**62)
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+
+# ==================== interpolation Tests ====================
+
+def test_interp():
+    assert rustdash.interp(2.5, [1, 2, 3], [3, 2, 0]) == 1.0
+    assert rustdash.interp([0, 1.5, 5], [1, 2, 3], [3, 2, 0]) == [3.0, 2.5, 0.0]
+
+def test_interp_unsorted_xp():
+    try:
+        rustdash.interp(1, [3, 2, 1], [1, 2, 3])
+        assert False, "expected ValueError"
+    except ValueError as e:
+        assert "xp" in str(e)
+
+def test_lerp():
+    assert rustdash.lerp(0, 10, 0.25) == 2.5
+    assert rustdash.lerp(0, 10, [0, 0.5, 1.5]) == [0.0, 5.0, 15.0]
+
+def test_fill_gaps():
+    values = [None, 1, None, None, 4, None]
+    assert rustdash.fill_gaps(values) == [None, 1.0, 2.0, 3.0, 4.0, None]
+    assert rustdash.fill_gaps(values, "ffill") == [None, 1.0, 1.0, 1.0, 4.0, 4.0]
+    assert rustdash.fill_gaps(values, "bfill") == [1.0, 1.0, 4.0, 4.0, 4.0, None]
+    try:
+        rustdash.fill_gaps(values, "nearest")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_resample():
+    points = [(0, 1), (30, 3), (130, 5), (61, 2)]
+    assert rustdash.resample(points, 60) == [(0, 2.0), (60, 2.0), (120, 5.0)]
+    assert rustdash.resample([(0, 1), (125, 2)], 60, agg="count") == [(0, 1), (60, 0), (120, 1)]
+    assert rustdash.resample([(0.5, 1.0), (1.7, 3.0)], 0.5, agg="max") == [
+        (0.5, 1.0), (1.0, None), (1.5, 3.0)
+    ]
+
+def test_resample_int_timestamps_are_exact():
+    base = 1_700_000_000_000_000_001
+    assert rustdash.resample([(base, 1), (base + 1, 2)], 2, agg="count") == [
+        (base - 1, 1), (base + 1, 1)
+    ]
+
+def test_resample_too_many_buckets_raises():
+    try:
+        rustdash.resample([(0, 1.0), (1_700_000_000_000, 2.0)], 1)
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+def test_resample_then_fill_gaps():
+    buckets = rustdash.resample([(0, 1.0), (180, 4.0)], 60)
+    values = rustdash.fill_gaps([v for _, v in buckets])
+    assert values == [1.0, 2.0, 3.0, 4.0]
@@ -0,0 +1,108 @@
+import rustdash
+
+
+# ==================== random Tests ====================
+
+def test_random_seeded_is_reproducible():
+    assert rustdash.random(0, 100, seed=1) == rustdash.random(0, 100, seed=1)
+    assert rustdash.random(0, 1, floating=True, seed=1) == rustdash.random(
+        0, 1, floating=True, seed=1
+    )
+
+def test_random_int_bounds():
+    for seed in range(200):
+        value = rustdash.random(1, 6, seed=seed)
+        assert isinstance(value, int)
+        assert 1 <= value <= 6
+
+def test_random_single_bound():
+    values = {rustdash.random(3, seed=seed) for seed in range(200)}
+    assert values == {0, 1, 2, 3}
+
+def test_random_float_bound_switches_to_float():
+    value = rustdash.random(1.5, seed=3)
+    assert isinstance(value, float)
+    assert 0 <= value < 1.5
+
+def test_random_swapped_bounds():
+    for seed in range(50):
+        assert -5 <= rustdash.random(5, -5, seed=seed) <= 5
+
+
+# ==================== shuffle / sample Tests ====================
+
+def test_shuffle_is_permutation():
+    items = list(range(20))
+    result = rustdash.shuffle(items, seed=9)
+    assert sorted(result) == items
+    assert result == rustdash.shuffle(items, seed=9)
+    assert items == list(range(20))
+
+def test_shuffle_empty():
+    assert rustdash.shuffle([], seed=1) == []
+
+def test_sample():
+    assert rustdash.sample(["a", "b", "c"], seed=4) in ["a", "b", "c"]
+    assert rustdash.sample([], seed=4) is None
+
+def test_sample_size():
+    result = rustdash.sample_size(list(range(10)), 4, seed=2)
+    assert len(result) == 4
+    assert len(set(result)) == 4
+    assert rustdash.sample_size([1, 2, 3], 10, seed=2) != []
+    assert sorted(rustdash.sample_size([1, 2, 3], 10, seed=2)) == [1, 2, 3]
+    assert rustdash.sample_size([1, 2, 3], 0) == []
+
+
+# ==================== weighted_choice Tests ====================
+
+def test_weighted_choice_zero_weight_never_chosen():
+    for seed in range(100):
+        assert rustdash.weighted_choice(["a", "b", "c"], [1, 0, 1], seed=seed) != "b"
+
+def test_weighted_choice_distribution():
+    picks = [rustdash.weighted_choice(["a", "b"], [9, 1], seed=s) for s in range(2000)]
+    assert 1650 < picks.count("a") < 1950
+
+def test_weighted_choice_invalid():
+    for weights in ([1, -1], [0, 0], [1]):
+        try:
+            rustdash.weighted_choice(["a", "b"], weights)
+            assert False, "expected ValueError"
+        except ValueError:
+            pass
+
+def test_weighted_choice_empty():
+    assert rustdash.weighted_choice([], []) is None
+
+
+# ==================== reservoir_sample Tests ====================
+
+def test_reservoir_sample_iterator():
+    result = rustdash.reservoir_sample((x for x in range(1000)), 5, seed=11)
+    assert len(result) == 5
+    assert len(set(result)) == 5
+    assert result == rustdash.reservoir_sample(iter(range(1000)), 5, seed=11)
+
+def test_reservoir_sample_short_stream():
+    assert rustdash.reservoir_sample([1, 2], 5, seed=1) == [1, 2]
+
+
+# ==================== Random Tests ====================
+
+def test_random_class_reproducible_stream():
+    a, b = rustdash.Random(123), rustdash.Random(123)
+    assert [a.random(0, 1000) for _ in range(5)] == [b.random(0, 1000) for _ in range(5)]
+    assert a.shuffle(list(range(10))) == b.shuffle(list(range(10)))
+
+def test_random_class_advances():
+    rng = rustdash.Random(5)
+    draws = [rng.random(0, 10**9) for _ in range(5)]
+    assert len(set(draws)) == 5
+
+def test_random_class_methods():
+    rng = rustdash.Random(8)
+    assert rng.sample([1, 2, 3]) in [1, 2, 3]
+    assert len(rng.sample_size([1, 2, 3], 2)) == 2
+    assert rng.weighted_choice(["x", "y"], [0, 1]) == "y"
+    assert len(rng.reservoir_sample(range(100), 3)) == 3